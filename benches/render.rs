@@ -0,0 +1,109 @@
+//! Measures the time and the number of heap allocations taken to render grids.
+//!
+//! Run with `cargo bench`.
+//!
+//! Rendering the grids of 1000 rows below, before and after the content lines
+//! were borrowed and the blanks written straight into the formatter:
+//!
+//! ```text
+//! grid         allocations          time
+//!            before   after    before   after
+//! left        13026    2037    2.64ms  1.79ms
+//! right       16026    2037    2.28ms  1.90ms
+//! center      16026    2037    2.77ms  2.20ms
+//! fill        13026    2037    4.49ms  1.98ms
+//! borders     16029    2037    2.88ms  2.00ms
+//! wrap        35126    8038    3.29ms  3.11ms
+//! auto size   10372    2040    2.23ms  2.08ms
+//! ```
+
+use cli_grid::{BorderStyle, Cell, Grid, HAlign, Overflow, Row};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+/// Allocator counting the allocations made through it.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const ROWS: usize = 1000;
+const MANY_ROWS: usize = 100_000;
+const ITERATIONS: u32 = 20;
+
+fn rows(count: usize) -> Vec<Row> {
+    (0..count)
+        .map(|i| {
+            Row::new(vec![
+                Cell::new(i.to_string(), 1),
+                Cell::new(format!("name {}", i), 1),
+                Cell::new("a longer description of the row".into(), 2),
+                Cell::new(format!("{}.{:02}", i * 7, i % 100), 1),
+            ])
+        })
+        .collect()
+}
+
+fn bench(name: &str, grid: &Grid) {
+    grid.write_to(&mut io::sink()).unwrap();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        grid.write_to(&mut io::sink()).unwrap();
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    let allocations = (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / ITERATIONS as usize;
+    println!(
+        "{:<12} {:>10.2?} {:>10} allocations",
+        name, elapsed, allocations
+    );
+}
+
+fn main() {
+    let aligned = |h_align| {
+        Grid::builder(rows(ROWS))
+            .default_h_align(h_align)
+            .column_width(12)
+            .build()
+    };
+    bench("left", &aligned(HAlign::Left));
+    bench("right", &aligned(HAlign::Right));
+    bench("center", &aligned(HAlign::Center));
+    bench("fill", &aligned(HAlign::Fill));
+    let borders = Grid::builder(rows(ROWS))
+        .border_style(BorderStyle::Light)
+        .column_width(12)
+        .build();
+    bench("borders", &borders);
+    let wrap = Grid::builder(rows(ROWS))
+        .default_overflow(Overflow::Wrap)
+        .column_width(8)
+        .build();
+    bench("wrap", &wrap);
+    let auto_size = Grid::builder(rows(ROWS)).auto_size(true).build();
+    bench("auto size", &auto_size);
+    let many_rows = Grid::builder(rows(MANY_ROWS)).column_width(12).build();
+    bench("many rows", &many_rows);
+}
@@ -0,0 +1,213 @@
+/// Set of chars used to draw the borders of a grid.
+#[derive(Clone, Copy, Debug)]
+pub struct BorderChars {
+    /// Horizontal line, e.g. `─`.
+    pub horizontal: char,
+
+    /// Vertical line, e.g. `│`.
+    pub vertical: char,
+
+    /// Top left corner, e.g. `┌`.
+    pub top_left: char,
+
+    /// Junction of a vertical line and the top border, e.g. `┬`.
+    pub top: char,
+
+    /// Top right corner, e.g. `┐`.
+    pub top_right: char,
+
+    /// Junction of a horizontal line and the left border, e.g. `├`.
+    pub left: char,
+
+    /// Crossing of a horizontal and a vertical line, e.g. `┼`.
+    pub cross: char,
+
+    /// Junction of a horizontal line and the right border, e.g. `┤`.
+    pub right: char,
+
+    /// Bottom left corner, e.g. `└`.
+    pub bottom_left: char,
+
+    /// Junction of a vertical line and the bottom border, e.g. `┴`.
+    pub bottom: char,
+
+    /// Bottom right corner, e.g. `┘`.
+    pub bottom_right: char,
+}
+
+impl BorderChars {
+    /// Returns the char joining the lines going out of a point in the given directions.
+    pub(crate) fn junction(&self, up: bool, down: bool, left: bool, right: bool) -> char {
+        match (up, down, left, right) {
+            (true, true, true, true) => self.cross,
+            (false, true, true, true) => self.top,
+            (true, false, true, true) => self.bottom,
+            (true, true, false, true) => self.left,
+            (true, true, true, false) => self.right,
+            (false, true, false, true) => self.top_left,
+            (false, true, true, false) => self.top_right,
+            (true, false, false, true) => self.bottom_left,
+            (true, false, true, false) => self.bottom_right,
+            (true, _, false, false) | (_, true, false, false) => self.vertical,
+            (false, false, _, _) => self.horizontal,
+        }
+    }
+}
+
+/// Styles of the borders of a grid.
+#[derive(Clone, Copy, Debug)]
+pub enum BorderStyle {
+    /// No borders are drawn. (default)
+    None,
+
+    /// ASCII chars, e.g. `+-+-+`.
+    Ascii,
+
+    /// Light box drawing chars, e.g. `┌─┬─┐`.
+    Light,
+
+    /// Heavy box drawing chars, e.g. `┏━┳━┓`.
+    Heavy,
+
+    /// Double box drawing chars, e.g. `╔═╦═╗`.
+    Double,
+
+    /// Light box drawing chars with rounded corners, e.g. `╭─┬─╮`.
+    Rounded,
+
+    /// Custom set of chars.
+    Custom(BorderChars),
+}
+
+impl BorderStyle {
+    /// Returns the chars of the style, or `None` for [`BorderStyle::None`].
+    ///
+    /// [`BorderStyle::None`]: enum.BorderStyle.html#variant.None
+    pub fn chars(&self) -> Option<BorderChars> {
+        let chars = match self {
+            BorderStyle::None => return None,
+            BorderStyle::Ascii => "-|+++++++++",
+            BorderStyle::Light => "─│┌┬┐├┼┤└┴┘",
+            BorderStyle::Heavy => "━┃┏┳┓┣╋┫┗┻┛",
+            BorderStyle::Double => "═║╔╦╗╠╬╣╚╩╝",
+            BorderStyle::Rounded => "─│╭┬╮├┼┤╰┴╯",
+            BorderStyle::Custom(chars) => return Some(*chars),
+        };
+        let mut chars = chars.chars();
+        let mut next = || chars.next().unwrap_or(' ');
+        Some(BorderChars {
+            horizontal: next(),
+            vertical: next(),
+            top_left: next(),
+            top: next(),
+            top_right: next(),
+            left: next(),
+            cross: next(),
+            right: next(),
+            bottom_left: next(),
+            bottom: next(),
+            bottom_right: next(),
+        })
+    }
+}
+
+/// Selects which borders of a grid are drawn.
+#[derive(Clone, Copy, Debug)]
+pub struct Borders {
+    /// Horizontal line above the first row.
+    pub top: bool,
+
+    /// Horizontal line below the last row.
+    pub bottom: bool,
+
+    /// Vertical line before the first column.
+    pub left: bool,
+
+    /// Vertical line after the last column.
+    pub right: bool,
+
+    /// Vertical separators between the cells of each row. They take the place
+    /// of the padding space between columns.
+    pub columns: bool,
+
+    /// Horizontal separators between the rows.
+    pub rows: bool,
+}
+
+impl Borders {
+    /// All the borders.
+    pub const ALL: Borders = Borders {
+        top: true,
+        bottom: true,
+        left: true,
+        right: true,
+        columns: true,
+        rows: true,
+    };
+
+    /// Only the outer frame.
+    pub const FRAME: Borders = Borders {
+        top: true,
+        bottom: true,
+        left: true,
+        right: true,
+        columns: false,
+        rows: false,
+    };
+
+    /// Only the separators between columns and rows.
+    pub const INNER: Borders = Borders {
+        top: false,
+        bottom: false,
+        left: false,
+        right: false,
+        columns: true,
+        rows: true,
+    };
+
+    /// Outer frame and separators between columns, but not between rows.
+    pub const COLUMNS: Borders = Borders {
+        top: true,
+        bottom: true,
+        left: true,
+        right: true,
+        columns: true,
+        rows: false,
+    };
+
+    /// No borders at all.
+    pub const NONE: Borders = Borders {
+        top: false,
+        bottom: false,
+        left: false,
+        right: false,
+        columns: false,
+        rows: false,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_junction_light() {
+        let chars = BorderStyle::Light.chars().unwrap();
+        let result = [
+            chars.junction(true, true, true, true),
+            chars.junction(false, true, true, true),
+            chars.junction(true, false, true, true),
+            chars.junction(false, false, true, true),
+            chars.junction(false, true, false, true),
+            chars.junction(true, false, true, false),
+        ];
+        let expected = ['┼', '┬', '┴', '─', '┌', '┘'];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_chars_none() {
+        let result = BorderStyle::None.chars().is_none();
+        assert!(result);
+    }
+}
@@ -1,12 +1,22 @@
 #![allow(dead_code)]
 
+use crate::{
+    error::{GridError, Location},
+    grid::Grid,
+    style::Style,
+};
+
 pub const DEFAULT_COLSPAN: usize = 1;
+pub const DEFAULT_ROWSPAN: usize = 1;
 pub const DEFAULT_H_ALIGN: HAlign = HAlign::Left;
 pub const DEFAULT_V_ALIGN: VAlign = VAlign::Top;
 pub const DEFAULT_BLANK_CHAR: char = '\x20';
+pub static DEFAULT_OVERFLOW: Overflow = Overflow::Truncate;
+pub const DEFAULT_PADDING: Padding = Padding::NONE;
+pub const DEFAULT_STYLE: Style = Style::PLAIN;
 
 /// Horizontal alignments for a cell.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HAlign {
     /// Left align contents of the cell. (default)
     Left,
@@ -19,10 +29,25 @@ pub enum HAlign {
 
     /// Fills the entire width of the cell with repeating the content.
     Fill,
+
+    /// Justifies the lines wrapped by [`Overflow::Wrap`], by adding blanks
+    /// after the white spaces between their words so that they take the whole
+    /// width of the cell. The last line of each paragraph is left aligned.
+    ///
+    /// [`Overflow::Wrap`]: enum.Overflow.html#variant.Wrap
+    Justify,
+
+    /// Aligns the lines of the cell on the first occurrence of the given
+    /// separator char, e.g. the decimal point of numbers. The cells covering
+    /// the same columns share the position of the separator, and the aligned
+    /// lines are right aligned as a whole. Lines without the separator end
+    /// where it would be. Lines without any digit, e.g. the title of the
+    /// column in a header, are right aligned and left out of the alignment.
+    Decimal(char),
 }
 
 /// Vertical alignments for a cell.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum VAlign {
     /// Top align contents of the cell. (default)
     Top,
@@ -34,12 +59,119 @@ pub enum VAlign {
     Middle,
 }
 
+/// Behaviour of a cell when a line of its content is wider than the cell.
+#[derive(Clone, Debug)]
+pub enum Overflow {
+    /// Cut the line at the width of the cell. (default)
+    Truncate,
+
+    /// Wrap the line on word boundaries into additional lines. Words wider than
+    /// the cell are broken at char boundaries.
+    Wrap,
+
+    /// Cut the line at the width of the cell and mark the cut by the `marker`
+    /// string (e.g. `"…"` or `"..."`) placed at the given `position`.
+    Ellipsis {
+        /// The string that replaces the cut part of the line.
+        marker: String,
+
+        /// Which part of the line is cut.
+        position: EllipsisPosition,
+    },
+}
+
+/// Position of the marker of an [`Overflow::Ellipsis`] in a truncated line.
+///
+/// [`Overflow::Ellipsis`]: enum.Overflow.html#variant.Ellipsis
+#[derive(Clone, Copy, Debug)]
+pub enum EllipsisPosition {
+    /// Cut the beginning of the line, e.g. `"…line"`.
+    Start,
+
+    /// Cut the middle of the line, e.g. `"a l…ine"`.
+    Middle,
+
+    /// Cut the end of the line, e.g. `"a lo…"`.
+    End,
+}
+
+/// Blank space inside a cell around its content, in chars on the left and the
+/// right sides and in lines on the top and the bottom sides. It is filled by
+/// the blank char of the cell.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Padding {
+    pub left: usize,
+    pub right: usize,
+    pub top: usize,
+    pub bottom: usize,
+}
+
+impl Padding {
+    /// No padding on any side. (default)
+    pub const NONE: Padding = Padding {
+        left: 0,
+        right: 0,
+        top: 0,
+        bottom: 0,
+    };
+
+    /// Padding of `size` chars on the left and the right sides only.
+    pub const fn horizontal(size: usize) -> Self {
+        Padding {
+            left: size,
+            right: size,
+            top: 0,
+            bottom: 0,
+        }
+    }
+
+    /// Padding of `size` chars, or lines, on every side.
+    pub const fn uniform(size: usize) -> Self {
+        Padding {
+            left: size,
+            right: size,
+            top: size,
+            bottom: size,
+        }
+    }
+}
+
+/// Content of a [`Cell`].
+///
+/// [`Cell`]: struct.Cell.html
+#[derive(Debug)]
+pub enum CellContent {
+    /// A string, possibly of multiple lines.
+    Text(String),
+
+    /// A nested [`Grid`]. It is laid out against the width of the cell, as if
+    /// its [`total_width`] was set to it, unless it sets its own. Where its
+    /// default options are unset, the ones of the cell are used.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`total_width`]: struct.Grid.html#structfield.total_width
+    Grid(Box<Grid>),
+}
+
+impl From<String> for CellContent {
+    fn from(text: String) -> Self {
+        CellContent::Text(text)
+    }
+}
+
+impl From<Grid> for CellContent {
+    fn from(grid: Grid) -> Self {
+        CellContent::Grid(Box::new(grid))
+    }
+}
+
 /// Data type that represents options for a cell and its content.
+#[derive(Debug)]
 pub struct Cell {
     /// The content of the cell. It can be a multi line string or even a nested [`Grid`].
     ///
     /// [`Grid`]: struct.Grid.html
-    pub content: String,
+    pub content: CellContent,
 
     /// Number of columns that this cell will be spreads out into.
     /// If `None` specified, the value [`col_span`] of the grid will
@@ -48,11 +180,19 @@ pub struct Cell {
     ///
     /// [`col_span`]: struct.Options.html#structfield.col_span
     ///
-    /// # Panics
+    /// A column span of `0` is invalid, see [`GridError::ZeroColSpan`].
     ///
-    /// Panics if `0` is specified.
+    /// [`GridError::ZeroColSpan`]: enum.GridError.html#variant.ZeroColSpan
     pub col_span: Option<usize>,
 
+    /// Number of rows that this cell will be spread out into, starting from
+    /// its own row. The cells of the rows below it are placed after the
+    /// columns it covers. If `None` specified, value 1 will be used.
+    ///
+    /// A cell never spans beyond the section of the grid it belongs to, i.e.
+    /// the header, a page of rows or the footer.
+    pub row_span: Option<usize>,
+
     /// Align content of the cell horizontally. If `None` specified,
     /// the value [`h_align`] of the grid will be used. If [`h_align`]
     /// of the grid also is `None`, [`HAlign::Left`] will be used.
@@ -75,6 +215,30 @@ pub struct Cell {
     ///
     /// [`blank_char`]: struct.Options.html#structfield.blank_char
     pub blank_char: Option<char>,
+
+    /// Behaviour of the cell when its content is wider than the cell. If `None`
+    /// specified, the value [`overflow`] of the grid will be used. If [`overflow`]
+    /// of the grid also is `None`, [`Overflow::Truncate`] will be used.
+    ///
+    /// [`overflow`]: struct.Options.html#structfield.overflow
+    /// [`Overflow::Truncate`]: enum.Overflow.html#variant.Truncate
+    pub overflow: Option<Overflow>,
+
+    /// Blank space around the content inside the cell. If `None` specified,
+    /// the value [`padding`] of the grid will be used. If [`padding`] of the
+    /// grid also is `None`, [`Padding::NONE`] will be used.
+    ///
+    /// [`padding`]: struct.Options.html#structfield.padding
+    /// [`Padding::NONE`]: struct.Padding.html#associatedconstant.NONE
+    pub padding: Option<Padding>,
+
+    /// Colours and text attributes of the cell. If `None` specified, the value
+    /// [`style`] of the grid will be used. If [`style`] of the grid also is
+    /// `None`, [`Style::PLAIN`] will be used.
+    ///
+    /// [`style`]: struct.Options.html#structfield.style
+    /// [`Style::PLAIN`]: struct.Style.html#associatedconstant.PLAIN
+    pub style: Option<Style>,
 }
 
 impl Cell {
@@ -84,26 +248,80 @@ impl Cell {
     /// [`Cell`]: struct.Cell.html
     /// [`col_span`]: struct.Cell.html#structfield.col_span
     /// [`builder`]: struct.Cell.html#method.builder
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col_span` is `0`. Use [`try_new`] to get an error instead.
+    ///
+    /// [`try_new`]: struct.Cell.html#method.try_new
     pub fn new(content: String, col_span: usize) -> Self {
         if col_span == 0 {
             panic!("Column span cannot be 0");
         }
+        Self::new_unchecked(content.into(), col_span)
+    }
+
+    /// Create a new [`Cell`] by its `content` and [`col_span`] properties, or
+    /// returns an error if `col_span` is `0`.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`col_span`]: struct.Cell.html#structfield.col_span
+    pub fn try_new(content: String, col_span: usize) -> Result<Self, GridError> {
+        let cell = Self::new_unchecked(content.into(), col_span);
+        cell.validate()?;
+        Ok(cell)
+    }
+
+    fn new_unchecked(content: CellContent, col_span: usize) -> Self {
         Self {
             content,
             col_span: Some(col_span),
+            row_span: None,
             h_align: None,
             v_align: None,
             blank_char: None,
+            overflow: None,
+            padding: None,
+            style: None,
         }
     }
 
+    /// Checks that the spans of the cell are not `0`, and that the grid nested
+    /// into it, if any, is valid.
+    pub(crate) fn validate(&self) -> Result<(), GridError> {
+        if self.col_span == Some(0) {
+            return Err(GridError::ZeroColSpan(Location::Standalone));
+        }
+        if self.row_span == Some(0) {
+            return Err(GridError::ZeroRowSpan(Location::Standalone));
+        }
+        if let CellContent::Grid(grid) = &self.content {
+            grid.validate()
+                .map_err(|e| GridError::Nested(Location::Standalone, Box::new(e)))?;
+        }
+        Ok(())
+    }
+
     /// Creates a [`Cell`] with the specified [`col_span`].
     /// The entire width of the cell will be filled by repeating the content.
     ///
     /// [`Cell`]: struct.Cell.html
     /// [`col_span`]: struct.Cell.html#structfield.col_span
     pub fn new_fill(content: String, col_span: usize) -> Self {
-        Cell::builder(content, col_span).h_align(HAlign::Fill).build()
+        Cell::builder(content, col_span)
+            .h_align(HAlign::Fill)
+            .build()
+    }
+
+    /// Creates a [`Cell`] with the specified [`col_span`], holding a nested [`Grid`].
+    /// To specify other properties, use the [`builder`] method instead.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`col_span`]: struct.Cell.html#structfield.col_span
+    /// [`Grid`]: struct.Grid.html
+    /// [`builder`]: struct.Cell.html#method.builder
+    pub fn new_grid(grid: Grid, col_span: usize) -> Self {
+        Cell::builder(String::new(), col_span).grid(grid).build()
     }
 
     /// Creates an empty [`Cell`] with the specified [`col_span`].
@@ -124,7 +342,7 @@ impl Cell {
     /// [`build`]: struct.CellBuilder.html#method.build
     pub fn builder(content: String, col_span: usize) -> CellBuilder {
         CellBuilder {
-            inner: Cell::new(content, col_span),
+            inner: Cell::new_unchecked(content.into(), col_span),
         }
     }
 }
@@ -132,6 +350,7 @@ impl Cell {
 /// Builder for the [`Cell`] type.
 ///
 /// [`Cell`]: struct.CEll.html
+#[derive(Debug)]
 pub struct CellBuilder {
     inner: Cell,
 }
@@ -145,6 +364,16 @@ impl CellBuilder {
         self.inner
     }
 
+    /// Builds a [`Cell`] from a [`CellBuilder`], or returns an error if its
+    /// column span or row span is `0`.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`CellBuilder`]: struct.CellBuilder.html
+    pub fn try_build(self) -> Result<Cell, GridError> {
+        self.inner.validate()?;
+        Ok(self.inner)
+    }
+
     /// Sets the content of the cell. It can be a multi line string or even a nested [`Grid`].
     /// To build the final [`Cell`] type, [`build`] method must be called.
     ///
@@ -152,7 +381,18 @@ impl CellBuilder {
     /// [`Cell`]: struct.Cell.html
     /// [`build`]: struct.CellBuilder.html#method.build
     pub fn content(mut self, content: String) -> Self {
-        self.inner.content = content;
+        self.inner.content = content.into();
+        self
+    }
+
+    /// Sets a nested [`Grid`] as the content of the cell.
+    /// To build the final [`Cell`] type, [`build`] method must be called.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Cell`]: struct.Cell.html
+    /// [`build`]: struct.CellBuilder.html#method.build
+    pub fn grid(mut self, grid: Grid) -> Self {
+        self.inner.content = grid.into();
         self
     }
 
@@ -166,6 +406,17 @@ impl CellBuilder {
         self
     }
 
+    /// Sets the number of rows the cell spans. The content is aligned
+    /// vertically over the combined height of the spanned rows.
+    /// To build the final [`Cell`] type, [`build`] method must be called.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`build`]: struct.CellBuilder.html#method.build
+    pub fn row_span(mut self, row_span: usize) -> Self {
+        self.inner.row_span = Some(row_span);
+        self
+    }
+
     /// Sets the horizontal alignment of the cell.
     /// To build the final [`Cell`] type, [`build`] method must be called.
     ///
@@ -195,4 +446,34 @@ impl CellBuilder {
         self.inner.blank_char = Some(blank_char);
         self
     }
+
+    /// Sets the overflow behaviour of the cell.
+    /// To build the final [`Cell`] type, [`build`] method must be called.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`build`]: struct.CellBuilder.html#method.build
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.inner.overflow = Some(overflow);
+        self
+    }
+
+    /// Sets the padding around the content of the cell.
+    /// To build the final [`Cell`] type, [`build`] method must be called.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`build`]: struct.CellBuilder.html#method.build
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.inner.padding = Some(padding);
+        self
+    }
+
+    /// Sets the colours and text attributes of the cell.
+    /// To build the final [`Cell`] type, [`build`] method must be called.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`build`]: struct.CellBuilder.html#method.build
+    pub fn style(mut self, style: Style) -> Self {
+        self.inner.style = Some(style);
+        self
+    }
 }
@@ -0,0 +1,227 @@
+use std::fmt;
+
+/// Section of a [`Grid`] a row belongs to.
+///
+/// [`Grid`]: struct.Grid.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Section {
+    /// The [`header`] rows.
+    ///
+    /// [`header`]: struct.Grid.html#structfield.header
+    Header,
+
+    /// The [`rows`] of the grid.
+    ///
+    /// [`rows`]: struct.Grid.html#structfield.rows
+    Body,
+
+    /// The [`footer`] rows.
+    ///
+    /// [`footer`]: struct.Grid.html#structfield.footer
+    Footer,
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Section::Header => write!(f, "header"),
+            Section::Body => write!(f, "body"),
+            Section::Footer => write!(f, "footer"),
+        }
+    }
+}
+
+/// Where in a [`Grid`] an invalid value was found. Rows and cells are given
+/// by their index in their section and their row.
+///
+/// [`Grid`]: struct.Grid.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Location {
+    /// A cell on its own, not part of a grid.
+    Standalone,
+
+    /// The default options of the grid.
+    Grid,
+
+    /// The default options of the header or the footer.
+    Section(Section),
+
+    /// The default options of a column.
+    Column(usize),
+
+    /// The default options of a row.
+    Row(Section, usize),
+
+    /// A cell of a row.
+    Cell(Section, usize, usize),
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Location::Standalone => write!(f, "cell"),
+            Location::Grid => write!(f, "grid options"),
+            Location::Section(section) => write!(f, "{} options", section),
+            Location::Column(column) => write!(f, "column {} options", column),
+            Location::Row(section, row) => write!(f, "{} row {}", section, row),
+            Location::Cell(section, row, cell) => {
+                write!(f, "{} row {} cell {}", section, row, cell)
+            }
+        }
+    }
+}
+
+/// Errors found when validating a [`Grid`] or a [`Cell`].
+///
+/// [`Grid`]: struct.Grid.html
+/// [`Cell`]: struct.Cell.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// A column span of 0.
+    ZeroColSpan(Location),
+
+    /// A row span of 0.
+    ZeroRowSpan(Location),
+
+    /// A cell spanning past the last row of its section.
+    RowSpanOutOfBounds(Location),
+
+    /// A cell covering a column that is already covered by a cell spanning
+    /// from a row above.
+    OverlappingCells(Location),
+
+    /// Rows covering fewer columns than the grid, with no [`RaggedRows`]
+    /// policy to normalise them.
+    ///
+    /// [`RaggedRows`]: enum.RaggedRows.html
+    UnevenRows {
+        /// Number of columns of the grid.
+        columns: usize,
+
+        /// Locations of the short rows.
+        rows: Vec<Location>,
+    },
+
+    /// A row setting its column widths, padding size or gutter char
+    /// differently from a row before it, while the grid leaves them unset.
+    ConflictingRowSettings(Location),
+
+    /// The minimum width of a column is greater than its maximum width.
+    ConflictingColumnWidths {
+        /// Index of the column.
+        column: usize,
+
+        /// Minimum width of the column.
+        min: usize,
+
+        /// Maximum width of the column.
+        max: usize,
+    },
+
+    /// The total width of the grid is too small to hold the columns at their
+    /// minimum widths.
+    TotalWidthTooSmall {
+        /// The requested total width.
+        total_width: usize,
+
+        /// Width of the grid with every column at its minimum width, paddings
+        /// and borders included.
+        min_width: usize,
+    },
+
+    /// An error found in the grid nested into a cell.
+    Nested(Location, Box<GridError>),
+}
+
+impl GridError {
+    /// Returns the error with its location replaced by `location`.
+    pub(crate) fn at(self, location: Location) -> Self {
+        match self {
+            GridError::ZeroColSpan(_) => GridError::ZeroColSpan(location),
+            GridError::ZeroRowSpan(_) => GridError::ZeroRowSpan(location),
+            GridError::RowSpanOutOfBounds(_) => GridError::RowSpanOutOfBounds(location),
+            GridError::OverlappingCells(_) => GridError::OverlappingCells(location),
+            GridError::ConflictingRowSettings(_) => GridError::ConflictingRowSettings(location),
+            GridError::Nested(_, error) => GridError::Nested(location, error),
+            error => error,
+        }
+    }
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::ZeroColSpan(location) => write!(f, "{}: column span cannot be 0", location),
+            GridError::ZeroRowSpan(location) => write!(f, "{}: row span cannot be 0", location),
+            GridError::RowSpanOutOfBounds(location) => {
+                write!(f, "{}: row span goes past the last row", location)
+            }
+            GridError::OverlappingCells(location) => {
+                write!(f, "{}: overlaps a cell spanning from a row above", location)
+            }
+            GridError::ConflictingRowSettings(location) => write!(
+                f,
+                "{}: column widths, padding size or gutter char differ from a row above",
+                location
+            ),
+            GridError::UnevenRows { columns, rows } => {
+                write!(f, "rows covering fewer than {} columns:", columns)?;
+                for (i, row) in rows.iter().enumerate() {
+                    write!(f, "{} {}", if i == 0 { "" } else { "," }, row)?;
+                }
+                Ok(())
+            }
+            GridError::ConflictingColumnWidths { column, min, max } => write!(
+                f,
+                "column {}: minimum width {} is greater than maximum width {}",
+                column, min, max
+            ),
+            GridError::TotalWidthTooSmall {
+                total_width,
+                min_width,
+            } => write!(
+                f,
+                "total width {} is smaller than the minimum width {} of the grid",
+                total_width, min_width
+            ),
+            GridError::Nested(location, error) => write!(f, "{}: nested grid: {}", location, error),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_cell_location() {
+        let error = GridError::ZeroColSpan(Location::Cell(Section::Body, 2, 1));
+        let result = error.to_string();
+        let expected = "body row 2 cell 1: column span cannot be 0";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_display_uneven_rows() {
+        let error = GridError::UnevenRows {
+            columns: 3,
+            rows: vec![
+                Location::Row(Section::Header, 0),
+                Location::Row(Section::Body, 4),
+            ],
+        };
+        let result = error.to_string();
+        let expected = "rows covering fewer than 3 columns: header row 0, body row 4";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_at_replaces_location() {
+        let error = GridError::ZeroRowSpan(Location::Standalone);
+        let result = error.at(Location::Cell(Section::Header, 0, 3));
+        let expected = GridError::ZeroRowSpan(Location::Cell(Section::Header, 0, 3));
+        assert_eq!(result, expected);
+    }
+}
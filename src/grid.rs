@@ -1,10 +1,51 @@
 #![allow(dead_code)]
 
-use crate::{options::Options, row::Row, HAlign, VAlign};
+use crate::{
+    border::{BorderChars, BorderStyle, Borders},
+    cell::DEFAULT_BLANK_CHAR,
+    error::{GridError, Location, Section},
+    layout::{self, CellLayout, Entry, GridLayout, Layout},
+    options::Options,
+    render::{GridDisplay, RenderConfig},
+    row::Row,
+    rule::{CellContext, CellRule},
+    stream::GridWriter,
+    terminal,
+    theme::Theme,
+    HAlign, Overflow, Padding, Style, VAlign,
+};
+
+use std::io;
+
+/// Policies for the rows covering fewer columns than the grid.
+#[derive(Clone, Copy, Debug)]
+pub enum RaggedRows {
+    /// Render the rows as they are. Their right edges are not aligned unless
+    /// the grid has a right border. [`Grid::validate`] reports them. (default)
+    ///
+    /// [`Grid::validate`]: struct.Grid.html#method.validate
+    Keep,
+
+    /// Pad the rows with empty cells up to the last column.
+    Pad,
+
+    /// Extend the column span of the last cell of each row up to the last
+    /// column. Rows ending with a cell spanning from a row above are padded.
+    Stretch,
+}
+
+/// Default options of the rows, the header and the footer of a grid.
+#[derive(Debug)]
+struct Defaults {
+    rows: Options,
+    header: Options,
+    footer: Options,
+}
 
 /// Builder for the [`Grid`] type.
 ///
 /// [`Grid`]: struct.Grid.html
+#[derive(Debug)]
 pub struct GridBuilder {
     inner: Grid,
 }
@@ -14,19 +55,180 @@ pub struct GridBuilder {
 /// [`Grid`]: struct.Grid.html
 pub struct Grid {
     /// These options will be used if the equivalent is not provided
-    /// by the underlying [`Row`] type.
+    /// by the underlying [`Row`] type nor by [`column_options`].
     ///
     /// [`Row`]: struct.Row.html
+    /// [`column_options`]: struct.Grid.html#structfield.column_options
     pub default_options: Options,
 
-    /// Width in chars for each column of the [`Grid`].
+    /// Width in chars for each column of the [`Grid`]. If `None` specified,
+    /// the [`column_width`] set by the rows is used. Rows setting different
+    /// ones make the grid invalid, see [`validate`].
     ///
     /// [`Grid`]: struct.Grid.html
+    /// [`column_width`]: struct.Row.html#structfield.column_width
+    /// [`validate`]: struct.Grid.html#method.validate
     pub column_width: Option<usize>,
 
-    /// Number of char spaces for each padding space between grid columns.
+    /// Width in chars for each individual column of the [`Grid`]. Columns
+    /// without an entry fall back to [`column_width`]. If `None` specified,
+    /// the [`column_widths`] set by the rows are used. Rows setting different
+    /// ones make the grid invalid, see [`validate`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`column_width`]: struct.Grid.html#structfield.column_width
+    /// [`column_widths`]: struct.Row.html#structfield.column_widths
+    /// [`validate`]: struct.Grid.html#method.validate
+    pub column_widths: Option<Vec<usize>>,
+
+    /// If `true`, the width of each column is computed from the content of the cells,
+    /// so that every cell fits into the columns it spans. [`column_width`] and
+    /// [`column_widths`] are ignored in this mode.
+    ///
+    /// [`column_width`]: struct.Grid.html#structfield.column_width
+    /// [`column_widths`]: struct.Grid.html#structfield.column_widths
+    pub auto_size: Option<bool>,
+
+    /// Total width of the [`Grid`] in chars, paddings included. If specified, the
+    /// columns are shrunk or grown proportionally to fit into this width.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub total_width: Option<usize>,
+
+    /// Minimum width of each column when fitting the grid into [`total_width`].
+    /// Columns without an entry can not get narrower than 1 char.
+    ///
+    /// [`total_width`]: struct.Grid.html#structfield.total_width
+    pub min_column_widths: Option<Vec<usize>>,
+
+    /// Maximum width of each column when fitting the grid into [`total_width`].
+    /// Columns without an entry are unbounded.
+    ///
+    /// [`total_width`]: struct.Grid.html#structfield.total_width
+    pub max_column_widths: Option<Vec<usize>>,
+
+    /// Default options of each individual column of the [`Grid`]. They are used
+    /// for the cells starting in the column if the equivalent is not provided
+    /// by the cell nor its [`Row`], before falling back to [`default_options`]
+    /// (or to [`header_options`] and [`footer_options`] for those rows).
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Row`]: struct.Row.html
+    /// [`default_options`]: struct.Grid.html#structfield.default_options
+    /// [`header_options`]: struct.Grid.html#structfield.header_options
+    /// [`footer_options`]: struct.Grid.html#structfield.footer_options
+    pub column_options: Option<Vec<Options>>,
+
+    /// Number of char spaces for each padding space between grid columns. If
+    /// `None` specified, the [`padding_size`] set by the rows is used. Rows
+    /// setting different ones make the grid invalid, see [`validate`].
+    ///
+    /// [`padding_size`]: struct.Row.html#structfield.padding_size
+    /// [`validate`]: struct.Grid.html#method.validate
     pub padding_size: Option<usize>,
 
+    /// Char filling the padding spaces between grid columns. If `None`
+    /// specified, the [`gutter_char`] set by the rows is used, or white space
+    /// char (`'\x20'`) if none does. Rows setting different ones make the grid
+    /// invalid, see [`validate`]. Column borders are drawn instead if the grid
+    /// has any.
+    ///
+    /// [`gutter_char`]: struct.Row.html#structfield.gutter_char
+    /// [`validate`]: struct.Grid.html#method.validate
+    pub gutter_char: Option<char>,
+
+    /// Style of the borders of the [`Grid`]. If `None` specified, no borders are drawn.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub border_style: Option<BorderStyle>,
+
+    /// Selects which borders are drawn if a [`border_style`] is specified. If `None`
+    /// specified, all the borders are drawn.
+    ///
+    /// [`border_style`]: struct.Grid.html#structfield.border_style
+    pub borders: Option<Borders>,
+
+    /// Rows rendered above the [`rows`] of the grid.
+    ///
+    /// [`rows`]: struct.Grid.html#structfield.rows
+    pub header: Vec<Row>,
+
+    /// These options will be used for the [`header`] rows if the equivalent is
+    /// not provided by the underlying [`Row`] type, before falling back to
+    /// [`default_options`].
+    ///
+    /// [`header`]: struct.Grid.html#structfield.header
+    /// [`Row`]: struct.Row.html
+    /// [`default_options`]: struct.Grid.html#structfield.default_options
+    pub header_options: Options,
+
+    /// If specified, a line of this char is drawn below the [`header`]. If the
+    /// grid has borders, a border line is drawn instead.
+    ///
+    /// [`header`]: struct.Grid.html#structfield.header
+    pub header_separator: Option<char>,
+
+    /// Rows rendered below the [`rows`] of the grid.
+    ///
+    /// [`rows`]: struct.Grid.html#structfield.rows
+    pub footer: Vec<Row>,
+
+    /// These options will be used for the [`footer`] rows if the equivalent is
+    /// not provided by the underlying [`Row`] type, before falling back to
+    /// [`default_options`].
+    ///
+    /// [`footer`]: struct.Grid.html#structfield.footer
+    /// [`Row`]: struct.Row.html
+    /// [`default_options`]: struct.Grid.html#structfield.default_options
+    pub footer_options: Options,
+
+    /// If specified, a line of this char is drawn above the [`footer`]. If the
+    /// grid has borders, a border line is drawn instead.
+    ///
+    /// [`footer`]: struct.Grid.html#structfield.footer
+    pub footer_separator: Option<char>,
+
+    /// Options of every other stripe of [`stripe_size`] [`rows`], starting from
+    /// the second one, e.g. a background [`Style`] alternating every few rows.
+    /// They are used for the cells of the striped rows if the equivalent is not
+    /// provided by the cell nor its [`Row`], before falling back to
+    /// [`column_options`] and [`default_options`]. Their column span is ignored.
+    /// The background of their style also fills the gutters and the uncovered
+    /// columns of the striped rows.
+    ///
+    /// [`stripe_size`]: struct.Grid.html#structfield.stripe_size
+    /// [`rows`]: struct.Grid.html#structfield.rows
+    /// [`Style`]: struct.Style.html
+    /// [`Row`]: struct.Row.html
+    /// [`column_options`]: struct.Grid.html#structfield.column_options
+    /// [`default_options`]: struct.Grid.html#structfield.default_options
+    pub stripe_options: Options,
+
+    /// Number of [`rows`] in each stripe of the grid. A row of many lines is
+    /// counted once. If `None` specified, the stripes are 1 row high.
+    ///
+    /// [`rows`]: struct.Grid.html#structfield.rows
+    pub stripe_size: Option<usize>,
+
+    /// Rules overriding the options of the cells when the grid is rendered, in
+    /// order of increasing precedence. See [`GridBuilder::rule`].
+    ///
+    /// [`GridBuilder::rule`]: struct.GridBuilder.html#method.rule
+    pub rules: Vec<CellRule>,
+
+    /// Number of [`rows`] in each page of the grid. If specified, the [`header`]
+    /// is repeated at the start of every page.
+    ///
+    /// [`rows`]: struct.Grid.html#structfield.rows
+    /// [`header`]: struct.Grid.html#structfield.header
+    pub page_size: Option<usize>,
+
+    /// Policy for the rows covering fewer columns than the grid. If `None`
+    /// specified, [`RaggedRows::Keep`] is used.
+    ///
+    /// [`RaggedRows::Keep`]: enum.RaggedRows.html#variant.Keep
+    pub ragged_rows: Option<RaggedRows>,
+
     /// Collection of rows that this [`Grid`] contains.
     ///
     /// [`Grid`]: struct.Grid.html
@@ -38,19 +240,33 @@ impl Grid {
     ///
     /// [`Grid`]: struct.Grid.html
     pub fn new(rows: Vec<Row>) -> Self {
-        let default_options = Options {
-            col_span: None,
-            h_align: None,
-            v_align: None,
-            blank_char: None,
-        };
-        let grid = Self {
+        let default_options = Options::default();
+        Self {
             default_options,
             column_width: None,
+            column_widths: None,
+            auto_size: None,
+            total_width: None,
+            min_column_widths: None,
+            max_column_widths: None,
+            column_options: None,
             padding_size: None,
+            gutter_char: None,
+            border_style: None,
+            borders: None,
+            header: Vec::new(),
+            header_options: Options::default(),
+            header_separator: None,
+            footer: Vec::new(),
+            footer_options: Options::default(),
+            footer_separator: None,
+            stripe_options: Options::default(),
+            stripe_size: None,
+            rules: Vec::new(),
+            page_size: None,
+            ragged_rows: None,
             rows,
-        };
-        grid
+        }
     }
 
     /// Creates a [`GridBuilder`] initiated with rows.
@@ -62,23 +278,540 @@ impl Grid {
         }
     }
 
-    /// Format the grid into a string.
-    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.rows {
-            row.render(
-                f,
-                &self.default_options,
-                self.column_width,
-                self.padding_size,
-            )?;
+    /// Returns the default options of the columns.
+    pub(crate) fn column_options(&self) -> &[Options] {
+        self.column_options.as_deref().unwrap_or(&[])
+    }
+
+    /// Returns the chars and the selection of the borders to draw, if any.
+    pub(crate) fn resolve_borders(&self) -> Option<(BorderChars, Borders)> {
+        let chars = self.border_style.as_ref()?.chars()?;
+        Some((chars, self.borders.unwrap_or(Borders::ALL)))
+    }
+
+    /// Checks the grid for invalid values: spans of `0`, cells spanning past
+    /// the last row of their section or overlapping each other, rows covering
+    /// fewer columns than the grid unless a [`ragged_rows`] policy normalises
+    /// them, rows setting different column widths, padding sizes or gutter
+    /// chars where the grid leaves them unset, and column widths that can not
+    /// be satisfied. Rendering an invalid grid never panics, but its output is
+    /// unspecified.
+    ///
+    /// [`ragged_rows`]: struct.Grid.html#structfield.ragged_rows
+    pub fn validate(&self) -> Result<(), GridError> {
+        let options = [
+            (&self.default_options, Location::Grid),
+            (&self.header_options, Location::Section(Section::Header)),
+            (&self.footer_options, Location::Section(Section::Footer)),
+        ];
+        for &(options, location) in options.iter() {
+            if options.col_span == Some(0) {
+                return Err(GridError::ZeroColSpan(location));
+            }
+        }
+        for (i, options) in self.column_options().iter().enumerate() {
+            if options.col_span == Some(0) {
+                return Err(GridError::ZeroColSpan(Location::Column(i)));
+            }
+        }
+        let header_options = self.header_options.or(&self.default_options);
+        let footer_options = self.footer_options.or(&self.default_options);
+        let sections = [
+            (Section::Header, &self.header, &header_options),
+            (Section::Body, &self.rows, &self.default_options),
+            (Section::Footer, &self.footer, &footer_options),
+        ];
+        let mut entries = Vec::new();
+        let mut locations = Vec::new();
+        let mut settings = RowSettings::default();
+        for (i, &(section, rows, default_options)) in sections.iter().enumerate() {
+            for (row_index, row) in rows.iter().enumerate() {
+                row.validate(section, row_index)?;
+                if !settings.agree(self, row) {
+                    let location = Location::Row(section, row_index);
+                    return Err(GridError::ConflictingRowSettings(location));
+                }
+                entries.push(Entry {
+                    row,
+                    default_options,
+                    separator: None,
+                    separator_below: None,
+                    section: i,
+                    stripe: None,
+                });
+                locations.push((section, row_index));
+            }
+        }
+        let layout = Layout::new(entries, self.column_options());
+        for (i, cell) in layout.cells.iter().enumerate() {
+            let (section, row) = locations[cell.row];
+            let location = Location::Cell(section, row, cell.index);
+            if cell.cell.row_span.unwrap_or(1) > cell.row_span {
+                return Err(GridError::RowSpanOutOfBounds(location));
+            }
+            if layout.overlaps(i) {
+                return Err(GridError::OverlappingCells(location));
+            }
+        }
+        if let RaggedRows::Keep = self.ragged_rows.unwrap_or(RaggedRows::Keep) {
+            let columns = layout.columns();
+            let rows = (0..locations.len())
+                .filter(|&row| layout.row_columns(row) < columns)
+                .map(|row| Location::Row(locations[row].0, locations[row].1))
+                .collect::<Vec<_>>();
+            if !rows.is_empty() {
+                return Err(GridError::UnevenRows { columns, rows });
+            }
+        }
+        let min_widths = self.min_column_widths.as_deref().unwrap_or(&[]);
+        let max_widths = self.max_column_widths.as_deref().unwrap_or(&[]);
+        for (column, (&min, &max)) in min_widths.iter().zip(max_widths).enumerate() {
+            if min > max {
+                return Err(GridError::ConflictingColumnWidths { column, min, max });
+            }
+        }
+        if let Some(total_width) = self.total_width {
+            let columns = layout.columns();
+            let min_width = (0..columns)
+                .map(|i| min_widths.get(i).copied().unwrap_or(1))
+                .sum::<usize>()
+                + self.gutter_size() * columns.saturating_sub(1)
+                + self.frame_size();
+            if total_width < min_width {
+                return Err(GridError::TotalWidthTooSmall {
+                    total_width,
+                    min_width,
+                });
+            }
         }
         Ok(())
     }
+
+    /// Returns the number of chars taken by the left and the right borders.
+    fn frame_size(&self) -> usize {
+        match self.resolve_borders() {
+            Some((_, borders)) => usize::from(borders.left) + usize::from(borders.right),
+            None => 0,
+        }
+    }
+
+    /// Returns the options of the stripe the row of the given index in the
+    /// [`rows`] falls into, if it is striped.
+    ///
+    /// [`rows`]: struct.Grid.html#structfield.rows
+    pub(crate) fn stripe(&self, row: usize) -> Option<&Options> {
+        let size = self.stripe_size.filter(|&size| size > 0).unwrap_or(1);
+        Some(&self.stripe_options).filter(|_| (row / size) % 2 == 1)
+    }
+
+    /// Returns the number of chars between two adjacent columns.
+    pub(crate) fn gutter_size(&self) -> usize {
+        match self.resolve_borders() {
+            Some((_, borders)) if borders.columns => 1,
+            _ => self
+                .padding_size
+                .or_else(|| self.row_setting(|row| row.padding_size))
+                .unwrap_or(1),
+        }
+    }
+
+    /// Returns the char filling the padding spaces between the columns.
+    pub(crate) fn gutter_char(&self) -> char {
+        self.gutter_char
+            .or_else(|| self.row_setting(|row| row.gutter_char))
+            .unwrap_or(DEFAULT_BLANK_CHAR)
+    }
+
+    /// Returns the widths of `columns` columns given by [`column_widths`] and
+    /// [`column_width`].
+    ///
+    /// [`column_widths`]: struct.Grid.html#structfield.column_widths
+    /// [`column_width`]: struct.Grid.html#structfield.column_width
+    pub(crate) fn fixed_column_widths(&self, columns: usize) -> Vec<usize> {
+        let column_widths = self
+            .column_widths
+            .as_deref()
+            .or_else(|| self.row_setting(|row| row.column_widths.as_deref()));
+        let column_width = self
+            .column_width
+            .or_else(|| self.row_setting(|row| row.column_width));
+        layout::column_widths(
+            columns,
+            column_width.unwrap_or(1),
+            column_widths.unwrap_or(&[]),
+        )
+    }
+
+    /// Returns the setting of the first row of the grid that sets it, the
+    /// header and the footer included. The grid falls back to the settings of
+    /// its rows where it leaves its own unset, which [`validate`] requires the
+    /// rows to agree on.
+    ///
+    /// [`validate`]: struct.Grid.html#method.validate
+    fn row_setting<'a, T>(&'a self, setting: impl Fn(&'a Row) -> Option<T>) -> Option<T> {
+        self.header
+            .iter()
+            .chain(&self.rows)
+            .chain(&self.footer)
+            .find_map(setting)
+    }
+
+    /// Returns the default options of the rows, the header and the footer,
+    /// falling back to `inherited` where the grid leaves them unset.
+    fn defaults(&self, inherited: &Options) -> Defaults {
+        let rows = self.default_options.or(inherited);
+        Defaults {
+            header: self.header_options.or(&rows),
+            footer: self.footer_options.or(&rows),
+            rows,
+        }
+    }
+
+    /// Lists the rows to render in order: the header, the rows and the footer.
+    /// The header is repeated at the start of every page. The header, each page
+    /// and the footer are separate sections.
+    fn entries<'a>(&'a self, defaults: &'a Defaults) -> (Vec<Entry<'a>>, Vec<(Section, usize)>) {
+        let mut entries = Vec::new();
+        let mut locations = Vec::new();
+        let mut body_row = 0;
+        let page_size = self.page_size.filter(|&size| size > 0);
+        let mut pages = self
+            .rows
+            .chunks(page_size.unwrap_or(self.rows.len().max(1)));
+        let mut page = pages.next();
+        let mut section = 0;
+        loop {
+            for (i, row) in self.header.iter().enumerate() {
+                entries.push(Entry {
+                    row,
+                    default_options: &defaults.header,
+                    separator: None,
+                    separator_below: self.header_separator.filter(|_| i + 1 == self.header.len()),
+                    section,
+                    stripe: None,
+                });
+                locations.push((Section::Header, i));
+            }
+            section += 1;
+            for row in page.unwrap_or(&[]) {
+                entries.push(Entry {
+                    row,
+                    default_options: &defaults.rows,
+                    separator: None,
+                    separator_below: None,
+                    section,
+                    stripe: self.stripe(body_row),
+                });
+                locations.push((Section::Body, body_row));
+                body_row += 1;
+            }
+            section += 1;
+            page = pages.next();
+            if page.is_none() {
+                break;
+            }
+        }
+        for (i, row) in self.footer.iter().enumerate() {
+            let separator = self
+                .footer_separator
+                .filter(|_| i == 0 && !entries.is_empty());
+            entries.push(Entry {
+                row,
+                default_options: &defaults.footer,
+                separator,
+                separator_below: None,
+                section,
+                stripe: None,
+            });
+            locations.push((Section::Footer, i));
+        }
+        (entries, locations)
+    }
+
+    /// Computes the width of each column, from the content of the cells if
+    /// [`auto_size`] is set or from [`column_widths`] and [`column_width`]
+    /// otherwise, then fits them into [`total_width`] if specified.
+    ///
+    /// [`auto_size`]: struct.Grid.html#structfield.auto_size
+    /// [`column_widths`]: struct.Grid.html#structfield.column_widths
+    /// [`column_width`]: struct.Grid.html#structfield.column_width
+    /// [`total_width`]: struct.Grid.html#structfield.total_width
+    pub(crate) fn resolve_column_widths(
+        &self,
+        layout: &Layout<'_>,
+        total_width: Option<usize>,
+    ) -> Vec<usize> {
+        let gutter_size = self.gutter_size();
+        let widths = if self.auto_size.unwrap_or(false) {
+            layout::auto_column_widths(&layout.spans(), gutter_size)
+        } else {
+            self.fixed_column_widths(layout.columns())
+        };
+        let total_width = match total_width {
+            Some(total_width) => total_width,
+            None => return widths,
+        };
+        layout::fit_column_widths(
+            &widths,
+            total_width.saturating_sub(self.frame_size()),
+            gutter_size,
+            self.min_column_widths.as_deref().unwrap_or(&[]),
+            self.max_column_widths.as_deref().unwrap_or(&[]),
+        )
+    }
+
+    /// Normalises the rows of the layout covering fewer than `columns` columns
+    /// according to [`ragged_rows`].
+    ///
+    /// [`ragged_rows`]: struct.Grid.html#structfield.ragged_rows
+    pub(crate) fn apply_ragged_rows(&self, layout: &mut Layout<'_>, columns: usize) {
+        match self.ragged_rows.unwrap_or(RaggedRows::Keep) {
+            RaggedRows::Keep => {}
+            RaggedRows::Pad => layout.pad = true,
+            RaggedRows::Stretch => {
+                layout.stretch(columns);
+                layout.pad = true;
+            }
+        }
+    }
+
+    /// Returns the chars and the selection of the borders to draw, or no
+    /// chars and no borders.
+    pub(crate) fn borders_or_none(&self) -> (Option<BorderChars>, Borders) {
+        match self.resolve_borders() {
+            Some((chars, borders)) => (Some(chars), borders),
+            None => (None, Borders::NONE),
+        }
+    }
+
+    /// Lays out and arranges the whole grid, fitted into `width` chars unless
+    /// it specifies its own [`total_width`]. Returns the layout along with
+    /// the section and the index in it of each of its rows.
+    ///
+    /// [`total_width`]: struct.Grid.html#structfield.total_width
+    fn arranged<'a>(
+        &'a self,
+        defaults: &'a Defaults,
+        width: Option<usize>,
+        color: bool,
+    ) -> (Layout<'a>, Vec<(Section, usize)>) {
+        let (entries, locations) = self.entries(defaults);
+        let mut layout = Layout::new(entries, self.column_options());
+        layout.apply_rules(&self.rules, &locations);
+        layout.gutter_char = self.gutter_char();
+        layout.color = color;
+        let columns = layout.columns();
+        self.apply_ragged_rows(&mut layout, columns);
+        let widths = self.resolve_column_widths(&layout, self.total_width.or(width));
+        let (chars, borders) = self.borders_or_none();
+        layout.arrange(widths, self.gutter_size(), chars, borders);
+        (layout, locations)
+    }
+
+    /// Writes the grid, along with the styles of its cells if `color` is `true`.
+    pub(crate) fn render(&self, f: &mut impl std::fmt::Write, color: bool) -> std::fmt::Result {
+        let defaults = self.defaults(&Options::default());
+        self.arranged(&defaults, None, color).0.render(f)
+    }
+
+    /// Renders the grid nested into a cell `width` chars wide. `inherited` are
+    /// the resolved options of the cell.
+    pub(crate) fn render_nested(&self, inherited: &Options, width: usize, color: bool) -> String {
+        let defaults = self.defaults(inherited);
+        let mut s = String::new();
+        // Rendering into a string never fails.
+        let _ = self
+            .arranged(&defaults, Some(width), color)
+            .0
+            .render(&mut s);
+        s
+    }
+
+    /// Returns the width of the grid nested into a cell before it is fitted
+    /// into the cell. `inherited` are the resolved options of the cell.
+    pub(crate) fn nested_width(&self, inherited: &Options) -> usize {
+        let defaults = self.defaults(inherited);
+        self.arranged(&defaults, None, false).0.size().0
+    }
+
+    /// Computes the layout of the grid as it is rendered: the width of the
+    /// columns, and the position and the resolved options of every cell.
+    pub fn layout(&self) -> GridLayout {
+        let defaults = self.defaults(&Options::default());
+        let (layout, locations) = self.arranged(&defaults, None, false);
+        let cells = layout
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                let (section, row) = locations[cell.row];
+                let (line, x, height) = layout.rect(i);
+                CellLayout {
+                    section,
+                    row,
+                    index: cell.index,
+                    column: cell.column,
+                    col_span: cell.col_span,
+                    row_span: cell.row_span,
+                    line,
+                    x,
+                    width: cell.width,
+                    height,
+                    h_align: cell.h_align,
+                    v_align: cell.v_align,
+                    blank_char: cell.blank_char,
+                    padding: cell.padding,
+                    style: cell.style,
+                }
+            })
+            .collect();
+        let (width, height) = layout.size();
+        GridLayout {
+            column_widths: layout.widths.clone(),
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Writes the grid into `writer`, without formatting it into a string first.
+    /// The layout of the whole grid is still computed up front. To write the
+    /// rows as soon as they are laid out, see [`writer`].
+    ///
+    /// The grid is written in many small pieces, so an unbuffered `writer`,
+    /// such as a [`File`], is best wrapped into a [`BufWriter`].
+    ///
+    /// [`writer`]: struct.Grid.html#method.writer
+    /// [`File`]: https://doc.rust-lang.org/std/fs/struct.File.html
+    /// [`BufWriter`]: https://doc.rust-lang.org/std/io/struct.BufWriter.html
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.display(RenderConfig::ALWAYS).write_to(writer)
+    }
+
+    /// Creates a [`GridWriter`] that writes the grid into `writer` part by part,
+    /// followed by the rows given to [`write_row`]. See [`GridWriter`] for how
+    /// the grid is laid out in this mode.
+    ///
+    /// [`GridWriter`]: struct.GridWriter.html
+    /// [`write_row`]: struct.GridWriter.html#method.write_row
+    pub fn writer<W: io::Write>(&self, writer: W) -> io::Result<GridWriter<'_, W>> {
+        self.display(RenderConfig::ALWAYS).writer(writer)
+    }
+
+    /// Writes the grid into `writer` followed by the `rows`, flushing each part
+    /// of the grid as soon as it is laid out. See [`GridWriter`] for how the
+    /// grid is laid out in this mode.
+    ///
+    /// [`GridWriter`]: struct.GridWriter.html
+    pub fn write_rows_to<I, W>(&self, rows: I, writer: W) -> io::Result<()>
+    where
+        I: IntoIterator<Item = Row>,
+        W: io::Write,
+    {
+        self.display(RenderConfig::ALWAYS)
+            .write_rows_to(rows, writer)
+    }
+
+    /// Returns the grid along with the given [`RenderConfig`], to be displayed
+    /// or written according to it. The [`Display`] of the grid itself, and its
+    /// other methods writing it, always write the styles of the cells.
+    ///
+    /// ```rust
+    /// use cli_grid::*;
+    ///
+    /// let grid = Grid::builder(vec![Row::new(vec![Cell::new("1".into(), 1)])])
+    ///     .default_style(Style::PLAIN.bold())
+    ///     .build();
+    /// let config = RenderConfig {
+    ///     color: ColorChoice::Never,
+    /// };
+    ///
+    /// assert_eq!(grid.display(config).to_string(), "1\n");
+    /// ```
+    ///
+    /// [`RenderConfig`]: struct.RenderConfig.html
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    pub fn display(&self, config: RenderConfig) -> GridDisplay<'_> {
+        GridDisplay::new(self, config)
+    }
+}
+
+/// Settings of the first rows of a grid setting them, among the ones the
+/// grid leaves unset.
+#[derive(Default)]
+struct RowSettings<'a> {
+    column_width: Option<usize>,
+    column_widths: Option<&'a [usize]>,
+    padding_size: Option<usize>,
+    gutter_char: Option<char>,
+}
+
+impl<'a> RowSettings<'a> {
+    /// Returns `false` if the row sets one of the settings left unset by the
+    /// grid differently from the rows before it.
+    fn agree(&mut self, grid: &Grid, row: &'a Row) -> bool {
+        let column_width = row.column_width.filter(|_| grid.column_width.is_none());
+        let column_widths = row
+            .column_widths
+            .as_deref()
+            .filter(|_| grid.column_widths.is_none());
+        let padding_size = row.padding_size.filter(|_| grid.padding_size.is_none());
+        let gutter_char = row.gutter_char.filter(|_| grid.gutter_char.is_none());
+        agree(&mut self.column_width, column_width)
+            && agree(&mut self.column_widths, column_widths)
+            && agree(&mut self.padding_size, padding_size)
+            && agree(&mut self.gutter_char, gutter_char)
+    }
+}
+
+/// Returns `false` if `value` differs from the `first` value set, which is
+/// set to `value` if it is unset yet.
+fn agree<T: PartialEq>(first: &mut Option<T>, value: Option<T>) -> bool {
+    match (first.as_ref(), value) {
+        (Some(first), Some(value)) => *first == value,
+        (Some(_), None) => true,
+        (None, value) => {
+            *first = value;
+            true
+        }
+    }
 }
 
 impl std::fmt::Display for Grid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        self.render(f)
+        self.render(f, true)
+    }
+}
+
+// Written by hand since the rules are closures, shown by their count.
+impl std::fmt::Debug for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Grid")
+            .field("default_options", &self.default_options)
+            .field("column_width", &self.column_width)
+            .field("column_widths", &self.column_widths)
+            .field("auto_size", &self.auto_size)
+            .field("total_width", &self.total_width)
+            .field("min_column_widths", &self.min_column_widths)
+            .field("max_column_widths", &self.max_column_widths)
+            .field("column_options", &self.column_options)
+            .field("padding_size", &self.padding_size)
+            .field("gutter_char", &self.gutter_char)
+            .field("border_style", &self.border_style)
+            .field("borders", &self.borders)
+            .field("header", &self.header)
+            .field("header_options", &self.header_options)
+            .field("header_separator", &self.header_separator)
+            .field("footer", &self.footer)
+            .field("footer_options", &self.footer_options)
+            .field("footer_separator", &self.footer_separator)
+            .field("stripe_options", &self.stripe_options)
+            .field("stripe_size", &self.stripe_size)
+            .field("rules", &format_args!("[{} rules]", self.rules.len()))
+            .field("page_size", &self.page_size)
+            .field("ragged_rows", &self.ragged_rows)
+            .field("rows", &self.rows)
+            .finish()
     }
 }
 
@@ -91,8 +824,27 @@ impl GridBuilder {
         self.inner
     }
 
+    /// Builds a [`Grid`] from a [`GridBuilder`], or returns an error if the grid
+    /// is invalid. See [`Grid::validate`] for the checks made.
+    ///
+    /// [`GridBuilder`]: struct.GridBuilder.html
+    /// [`Grid`]: struct.Grid.html
+    /// [`Grid::validate`]: struct.Grid.html#method.validate
+    pub fn try_build(self) -> Result<Grid, GridError> {
+        self.inner.validate()?;
+        Ok(self.inner)
+    }
+
     /// Sets the default column span for all the cells of the grid. If a cell specifies
     /// a column span it will be used instead of the grids default value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `default_colspan` is `0`. A `0` set through the
+    /// [`default_options`] is reported by [`try_build`] instead.
+    ///
+    /// [`default_options`]: struct.Grid.html#structfield.default_options
+    /// [`try_build`]: struct.GridBuilder.html#method.try_build
     pub fn default_colspan(mut self, default_colspan: usize) -> Self {
         if default_colspan == 0 {
             panic!("Column span cannot be 0!");
@@ -122,15 +874,256 @@ impl GridBuilder {
         self
     }
 
+    /// Sets the default overflow behaviour for all the cells of the grid. If a cell specifies
+    /// an overflow behaviour it will be used instead of the grids default value.
+    pub fn default_overflow(mut self, default_overflow: Overflow) -> Self {
+        self.inner.default_options.overflow = Some(default_overflow);
+        self
+    }
+
+    /// Sets the default padding for all the cells of the grid. If a cell specifies
+    /// a padding it will be used instead of the grids default value.
+    pub fn default_padding(mut self, default_padding: Padding) -> Self {
+        self.inner.default_options.padding = Some(default_padding);
+        self
+    }
+
+    /// Sets the default style for all the cells of the grid. If a cell specifies
+    /// a style it will be used instead of the grids default value.
+    pub fn default_style(mut self, default_style: Style) -> Self {
+        self.inner.default_options.style = Some(default_style);
+        self
+    }
+
     /// Width of each column in the grid in number of chars.
     pub fn column_width(mut self, column_width: usize) -> Self {
         self.inner.column_width = Some(column_width);
         self
     }
 
+    /// Width of each individual column in the grid in number of chars. Columns
+    /// that are not listed use the value of [`column_width`]. A cell spanning
+    /// multiple columns takes the sum of their widths and the paddings between them.
+    ///
+    /// [`column_width`]: struct.GridBuilder.html#method.column_width
+    pub fn column_widths(mut self, column_widths: Vec<usize>) -> Self {
+        self.inner.column_widths = Some(column_widths);
+        self
+    }
+
+    /// Sets the default options of each individual column in the grid. A cell
+    /// uses the options of the column it starts in if the equivalent is not
+    /// provided by the cell nor its row, before falling back to the defaults
+    /// of the grid. Columns that are not listed only use the latter.
+    pub fn column_options(mut self, column_options: Vec<Options>) -> Self {
+        self.inner.column_options = Some(column_options);
+        self
+    }
+
+    /// Computes the width of each column from the content of the cells, so that
+    /// nothing gets truncated. The extra width needed by a cell spanning multiple
+    /// columns is distributed across the columns it spans.
+    pub fn auto_size(mut self, auto_size: bool) -> Self {
+        self.inner.auto_size = Some(auto_size);
+        self
+    }
+
+    /// Fits the grid into `total_width` chars, paddings included, by shrinking or
+    /// growing the columns proportionally to their widths.
+    pub fn total_width(mut self, total_width: usize) -> Self {
+        self.inner.total_width = Some(total_width);
+        self
+    }
+
+    /// Fits the grid into the width of the terminal. See [`terminal_width`] for how
+    /// the width is detected. If it can not be detected, the grid is left as is.
+    ///
+    /// [`terminal_width`]: fn.terminal_width.html
+    pub fn fit_terminal(mut self) -> Self {
+        if let Some(width) = terminal::terminal_width() {
+            self.inner.total_width = Some(width);
+        }
+        self
+    }
+
+    /// Minimum width of each column when the grid is fitted into a total width.
+    pub fn min_column_widths(mut self, min_column_widths: Vec<usize>) -> Self {
+        self.inner.min_column_widths = Some(min_column_widths);
+        self
+    }
+
+    /// Maximum width of each column when the grid is fitted into a total width.
+    pub fn max_column_widths(mut self, max_column_widths: Vec<usize>) -> Self {
+        self.inner.max_column_widths = Some(max_column_widths);
+        self
+    }
+
+    /// Sets the style of the borders. All the borders are drawn unless
+    /// selected otherwise by [`borders`].
+    ///
+    /// [`borders`]: struct.GridBuilder.html#method.borders
+    pub fn border_style(mut self, border_style: BorderStyle) -> Self {
+        self.inner.border_style = Some(border_style);
+        self
+    }
+
+    /// Selects which borders are drawn. Has no effect unless a [`border_style`]
+    /// is set. The vertical separators between columns take the place of the
+    /// padding space.
+    ///
+    /// [`border_style`]: struct.GridBuilder.html#method.border_style
+    pub fn borders(mut self, borders: Borders) -> Self {
+        self.inner.borders = Some(borders);
+        self
+    }
+
+    /// Sets the header rows, rendered above the rows of the grid.
+    pub fn header(mut self, header: Vec<Row>) -> Self {
+        self.inner.header = header;
+        self
+    }
+
+    /// Sets the default options for the cells of the header. If a header row or
+    /// cell specifies an option it will be used instead. Options not specified here
+    /// fall back to the grids default values.
+    pub fn header_options(mut self, header_options: Options) -> Self {
+        self.inner.header_options = header_options;
+        self
+    }
+
+    /// Draws a line of `separator` chars below the header, or a border line
+    /// if the grid has borders.
+    pub fn header_separator(mut self, separator: char) -> Self {
+        self.inner.header_separator = Some(separator);
+        self
+    }
+
+    /// Sets the footer rows, rendered below the rows of the grid.
+    pub fn footer(mut self, footer: Vec<Row>) -> Self {
+        self.inner.footer = footer;
+        self
+    }
+
+    /// Sets the default options for the cells of the footer. If a footer row or
+    /// cell specifies an option it will be used instead. Options not specified here
+    /// fall back to the grids default values.
+    pub fn footer_options(mut self, footer_options: Options) -> Self {
+        self.inner.footer_options = footer_options;
+        self
+    }
+
+    /// Draws a line of `separator` chars above the footer, or a border line
+    /// if the grid has borders.
+    pub fn footer_separator(mut self, separator: char) -> Self {
+        self.inner.footer_separator = Some(separator);
+        self
+    }
+
+    /// Sets the options of every other stripe of rows. If a row or cell of a
+    /// striped row specifies an option it will be used instead. Options not
+    /// specified here fall back to the column options and the grids default values.
+    pub fn stripe_options(mut self, stripe_options: Options) -> Self {
+        self.inner.stripe_options = stripe_options;
+        self
+    }
+
+    /// Sets the number of rows of each stripe, alternately striped and not.
+    pub fn stripe_size(mut self, stripe_size: usize) -> Self {
+        self.inner.stripe_size = Some(stripe_size);
+        self
+    }
+
+    /// Applies the options of the `theme`, replacing the ones of the grid. The
+    /// methods called afterwards override them piecemeal.
+    ///
+    /// ```rust
+    /// use cli_grid::*;
+    ///
+    /// let grid = Grid::builder(vec![Row::new(vec![
+    ///     Cell::new("1".into(), 1),
+    ///     Cell::new("22".into(), 1),
+    /// ])])
+    /// .header(vec![Row::new(vec![
+    ///     Cell::new("a".into(), 1),
+    ///     Cell::new("b".into(), 1),
+    /// ])])
+    /// .theme(Theme::markdown())
+    /// .default_h_align(HAlign::Right)
+    /// .auto_size(true)
+    /// .build();
+    ///
+    /// let expected = format!(
+    ///     "{}\n{}\n{}\n",
+    ///     "| a |  b |",
+    ///     "|---|----|",
+    ///     "| 1 | 22 |",
+    /// );
+    ///
+    /// assert_eq!(grid.to_string(), expected);
+    /// ```
+    pub fn theme(mut self, theme: Theme) -> Self {
+        let grid = &mut self.inner;
+        grid.default_options = theme.default_options;
+        grid.header_options = theme.header_options;
+        grid.header_separator = theme.header_separator;
+        grid.padding_size = theme.padding_size;
+        grid.gutter_char = theme.gutter_char;
+        grid.border_style = theme.border_style;
+        grid.borders = theme.borders;
+        self
+    }
+
+    /// Adds a rule overriding the options of the cells when the grid is
+    /// rendered, e.g. to colour the negative numbers of a column. The options
+    /// returned for a cell take precedence over the ones of the cell itself,
+    /// and over the ones of the rules added before. Their column span is ignored.
+    ///
+    /// ```rust
+    /// use cli_grid::*;
+    ///
+    /// let grid = Grid::builder(vec![Row::new(vec![
+    ///     Cell::new("-1".into(), 1),
+    ///     Cell::new("1".into(), 1),
+    /// ])])
+    /// .rule(|cell| Options {
+    ///     style: Some(Style::PLAIN.fg(Color::Red)).filter(|_| cell.content().starts_with('-')),
+    ///     ..Options::default()
+    /// })
+    /// .column_width(2)
+    /// .build();
+    ///
+    /// assert_eq!(grid.to_string(), "\x1b[31m-1\x1b[0m 1 \n");
+    /// ```
+    pub fn rule(
+        mut self,
+        rule: impl Fn(&CellContext<'_>) -> Options + Send + Sync + 'static,
+    ) -> Self {
+        self.inner.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Splits the rows into pages of `page_size` rows and repeats the header at
+    /// the start of every page.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.inner.page_size = Some(page_size);
+        self
+    }
+
+    /// Sets the policy for the rows covering fewer columns than the grid.
+    pub fn ragged_rows(mut self, ragged_rows: RaggedRows) -> Self {
+        self.inner.ragged_rows = Some(ragged_rows);
+        self
+    }
+
     /// Width of each padding space in the grid in number of chars.
     pub fn padding_size(mut self, padding_size: usize) -> Self {
         self.inner.padding_size = Some(padding_size);
         self
     }
+
+    /// Char filling the padding spaces between grid columns.
+    pub fn gutter_char(mut self, gutter_char: char) -> Self {
+        self.inner.gutter_char = Some(gutter_char);
+        self
+    }
 }
@@ -0,0 +1,1408 @@
+use crate::{
+    border::{BorderChars, Borders},
+    cell::{
+        Cell, CellContent, HAlign, Overflow, Padding, VAlign, DEFAULT_BLANK_CHAR, DEFAULT_COLSPAN,
+        DEFAULT_H_ALIGN, DEFAULT_OVERFLOW, DEFAULT_PADDING, DEFAULT_ROWSPAN, DEFAULT_STYLE,
+        DEFAULT_V_ALIGN,
+    },
+    error::Section,
+    options::Options,
+    row::{self, Row},
+    rule::{CellContext, CellRule},
+    style::{Painter, Style},
+    text,
+};
+
+use std::{borrow::Cow, collections::HashMap, fmt, ops::Range};
+
+/// Computed layout of a [`Grid`], as returned by [`Grid::layout`].
+///
+/// [`Grid`]: struct.Grid.html
+/// [`Grid::layout`]: struct.Grid.html#method.layout
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GridLayout {
+    /// Width in chars of each column.
+    pub column_widths: Vec<usize>,
+
+    /// Every cell of the grid, in the order they are rendered. With a
+    /// [`page_size`] the header cells appear once per page.
+    ///
+    /// [`page_size`]: struct.Grid.html#structfield.page_size
+    pub cells: Vec<CellLayout>,
+
+    /// Width in chars of the widest line of the rendered grid.
+    pub width: usize,
+
+    /// Number of lines of the rendered grid.
+    pub height: usize,
+}
+
+/// Position of a cell in a rendered [`Grid`], along with its resolved options.
+///
+/// [`Grid`]: struct.Grid.html
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellLayout {
+    /// Section of the row of the cell.
+    pub section: Section,
+
+    /// Index of the row of the cell in its section.
+    pub row: usize,
+
+    /// Index of the cell in its row.
+    pub index: usize,
+
+    /// First column covered by the cell.
+    pub column: usize,
+
+    /// Number of columns covered by the cell.
+    pub col_span: usize,
+
+    /// Number of rows covered by the cell, clamped to the end of its section.
+    pub row_span: usize,
+
+    /// Index of the first line of the cell in the rendered grid, border and
+    /// separator lines included.
+    pub line: usize,
+
+    /// Number of chars preceding the cell on each of its lines.
+    pub x: usize,
+
+    /// Width of the cell in chars.
+    pub width: usize,
+
+    /// Number of lines of the cell.
+    pub height: usize,
+
+    /// Horizontal alignment of the content of the cell.
+    pub h_align: HAlign,
+
+    /// Vertical alignment of the content of the cell.
+    pub v_align: VAlign,
+
+    /// Char filling the blanks of the cell.
+    pub blank_char: char,
+
+    /// Blanks around the content of the cell, within its width and height.
+    pub padding: Padding,
+
+    /// Style of the cell, whether or not the grid is rendered with colours.
+    pub style: Style,
+}
+
+/// A row of the grid to lay out, along with the default options of the
+/// grid for it.
+pub(crate) struct Entry<'a> {
+    pub row: &'a Row,
+    pub default_options: &'a Options,
+
+    /// Char of the separator line drawn above the row, if any.
+    pub separator: Option<char>,
+
+    /// Char of the separator line drawn below the row, if any.
+    pub separator_below: Option<char>,
+
+    /// Section of the grid the row belongs to, e.g. the header or a page of
+    /// rows. Cells never span rows of different sections.
+    pub section: usize,
+
+    /// Options of the stripe the row falls into, if any. They come after the
+    /// options of the row, before the column options.
+    pub stripe: Option<&'a Options>,
+}
+
+/// A cell placed into the columns and rows of the grid, with its options resolved.
+pub(crate) struct Placement<'a> {
+    pub cell: &'a Cell,
+
+    /// Index of the cell in its row.
+    pub index: usize,
+
+    pub row: usize,
+    pub column: usize,
+    pub col_span: usize,
+    pub row_span: usize,
+    pub h_align: HAlign,
+    pub v_align: VAlign,
+    pub blank_char: char,
+    pub overflow: Cow<'a, Overflow>,
+    pub padding: Padding,
+    pub style: Style,
+
+    /// Width of the cell in chars, the gutters between its columns included.
+    pub width: usize,
+
+    /// Widths of the parts of the lines before and from the separator, shared
+    /// by the [`HAlign::Decimal`] cells covering the same columns.
+    ///
+    /// [`HAlign::Decimal`]: enum.HAlign.html#variant.Decimal
+    pub decimal: (usize, usize),
+
+    /// Range in [`Layout::lines`] of the lines of the content fitted into
+    /// the content width according to `overflow`.
+    pub lines: Range<usize>,
+}
+
+impl Placement<'_> {
+    /// Returns the width of the cell left to its content by the padding.
+    fn content_width(&self) -> usize {
+        self.width
+            .saturating_sub(self.padding.left + self.padding.right)
+    }
+
+    /// Returns the number of lines of the content along with the padding.
+    fn height(&self) -> usize {
+        self.lines.len() + self.padding.top + self.padding.bottom
+    }
+
+    /// Returns the resolved options of the cell, inherited by the grid nested
+    /// into it.
+    fn options(&self) -> Options {
+        Options {
+            col_span: None,
+            h_align: Some(self.h_align),
+            v_align: Some(self.v_align),
+            blank_char: Some(self.blank_char),
+            overflow: Some(self.overflow.clone().into_owned()),
+            padding: None,
+            style: Some(self.style),
+        }
+    }
+}
+
+/// Horizontal line drawn above a row.
+#[derive(Clone, Copy)]
+enum Rule {
+    /// Border line joined with the vertical borders. Cells spanning the rows
+    /// around it are drawn through it.
+    Border,
+
+    /// Line of the given char across the whole grid.
+    Line(char),
+
+    /// Lines of the given chars across the whole grid, one below the other.
+    Lines(char, char),
+}
+
+impl Rule {
+    /// Returns the rule drawn for the `separators` between two rows, if any.
+    /// With borders, `rows` draws a border line between every two rows.
+    fn new(chars: Option<BorderChars>, rows: bool, separators: &[char]) -> Option<Self> {
+        match separators {
+            _ if chars.is_some() => Some(Rule::Border).filter(|_| rows || !separators.is_empty()),
+            [] => None,
+            [c] => Some(Rule::Line(*c)),
+            [c, d, ..] => Some(Rule::Lines(*c, *d)),
+        }
+    }
+
+    /// Returns the number of lines of the rule.
+    fn height(self) -> usize {
+        match self {
+            Rule::Border | Rule::Line(_) => 1,
+            Rule::Lines(..) => 2,
+        }
+    }
+}
+
+/// Row above a horizontal border line.
+#[derive(Clone, Copy)]
+enum Above {
+    /// A row of the layout.
+    Row(usize),
+
+    /// The row written right before the layout.
+    Previous,
+}
+
+/// Layout of the whole grid: the placement of every cell in the columns and
+/// rows, the width of the columns and the height of the rows.
+pub(crate) struct Layout<'a> {
+    pub entries: Vec<Entry<'a>>,
+    pub cells: Vec<Placement<'a>>,
+
+    /// Lines of the content of all the cells, borrowed from the cells unless
+    /// fitting them into their width changed them.
+    pub lines: Vec<Cow<'a, str>>,
+
+    /// Whether each of `lines` is followed by a line wrapped from the same
+    /// line of content.
+    continued: Vec<bool>,
+
+    /// Index in `cells` of the cell covering each column of each row.
+    slots: Vec<Vec<Option<usize>>>,
+
+    pub widths: Vec<usize>,
+
+    /// Number of lines of each row, separator lines excluded.
+    pub heights: Vec<usize>,
+
+    /// Index of the first line of each row, separator lines included.
+    pub tops: Vec<usize>,
+
+    /// Whether rows covering fewer columns than the grid are padded with
+    /// blanks up to the last column, even without a right border.
+    pub pad: bool,
+
+    /// Slots of the row written right before the layout, when a grid is
+    /// written in parts. Border lines above the first row are joined with it.
+    pub previous: Option<Vec<Option<usize>>>,
+
+    /// Char filling the gutters between the columns without a column border.
+    pub gutter_char: char,
+
+    /// Whether the styles of the cells are written.
+    pub color: bool,
+
+    rules: Vec<Option<Rule>>,
+    chars: Option<BorderChars>,
+    borders: Borders,
+    gutter_size: usize,
+}
+
+impl<'a> Layout<'a> {
+    /// Places the cells of the rows into the columns of the grid. A cell goes
+    /// into the first column of its row not covered by a cell spanning from
+    /// a row above.
+    ///
+    /// The options of a cell are resolved from the cell, its row, the stripe of
+    /// its entry, the entry of `column_options` of its starting column and the
+    /// defaults of its entry.
+    pub(crate) fn new(entries: Vec<Entry<'a>>, column_options: &'a [Options]) -> Self {
+        let mut cells = Vec::new();
+        let mut slots = vec![Vec::new(); entries.len()];
+        let mut section_ends = vec![entries.len(); entries.len()];
+        for index in (1..entries.len()).rev() {
+            section_ends[index - 1] = if entries[index - 1].section == entries[index].section {
+                section_ends[index]
+            } else {
+                index
+            };
+        }
+        for (index, entry) in entries.iter().enumerate() {
+            let row = entry.row;
+            let options = &row.default_options;
+            let defaults = entry.default_options;
+            let stripe = entry.stripe;
+            let section_end = section_ends[index];
+            let mut column = 0;
+            for (cell_index, cell) in row.cells.iter().enumerate() {
+                while slots[index].get(column).is_some_and(Option::is_some) {
+                    column += 1;
+                }
+                let column_options = column_options.get(column);
+                let col_span = cell
+                    .col_span
+                    .or(options.col_span)
+                    .or(column_options.and_then(|o| o.col_span))
+                    .or(defaults.col_span)
+                    .unwrap_or(DEFAULT_COLSPAN);
+                let row_span = cell
+                    .row_span
+                    .unwrap_or(DEFAULT_ROWSPAN)
+                    .max(1)
+                    .min(section_end - index);
+                for row_slots in &mut slots[index..index + row_span] {
+                    if row_slots.len() < column + col_span {
+                        row_slots.resize(column + col_span, None);
+                    }
+                    for slot in &mut row_slots[column..column + col_span] {
+                        slot.get_or_insert(cells.len());
+                    }
+                }
+                cells.push(Placement {
+                    cell,
+                    index: cell_index,
+                    row: index,
+                    column,
+                    col_span,
+                    row_span,
+                    h_align: cell
+                        .h_align
+                        .or(options.h_align)
+                        .or(stripe.and_then(|o| o.h_align))
+                        .or(column_options.and_then(|o| o.h_align))
+                        .or(defaults.h_align)
+                        .unwrap_or(DEFAULT_H_ALIGN),
+                    v_align: cell
+                        .v_align
+                        .or(options.v_align)
+                        .or(stripe.and_then(|o| o.v_align))
+                        .or(column_options.and_then(|o| o.v_align))
+                        .or(defaults.v_align)
+                        .unwrap_or(DEFAULT_V_ALIGN),
+                    blank_char: cell
+                        .blank_char
+                        .or(options.blank_char)
+                        .or(stripe.and_then(|o| o.blank_char))
+                        .or(column_options.and_then(|o| o.blank_char))
+                        .or(defaults.blank_char)
+                        .unwrap_or(DEFAULT_BLANK_CHAR),
+                    overflow: Cow::Borrowed(
+                        cell.overflow
+                            .as_ref()
+                            .or(options.overflow.as_ref())
+                            .or(stripe.and_then(|o| o.overflow.as_ref()))
+                            .or(column_options.and_then(|o| o.overflow.as_ref()))
+                            .or(defaults.overflow.as_ref())
+                            .unwrap_or(&DEFAULT_OVERFLOW),
+                    ),
+                    padding: cell
+                        .padding
+                        .or(options.padding)
+                        .or(stripe.and_then(|o| o.padding))
+                        .or(column_options.and_then(|o| o.padding))
+                        .or(defaults.padding)
+                        .unwrap_or(DEFAULT_PADDING),
+                    style: cell
+                        .style
+                        .or(options.style)
+                        .or(stripe.and_then(|o| o.style))
+                        .or(column_options.and_then(|o| o.style))
+                        .or(defaults.style)
+                        .unwrap_or(DEFAULT_STYLE),
+                    width: 0,
+                    decimal: (0, 0),
+                    lines: 0..0,
+                });
+                column += col_span;
+            }
+        }
+        let rows = entries.len();
+        Self {
+            entries,
+            cells,
+            lines: Vec::new(),
+            continued: Vec::new(),
+            slots,
+            widths: Vec::new(),
+            heights: vec![0; rows],
+            tops: vec![0; rows],
+            pad: false,
+            previous: None,
+            gutter_char: DEFAULT_BLANK_CHAR,
+            color: true,
+            rules: vec![None; rows + 1],
+            chars: None,
+            borders: Borders::NONE,
+            gutter_size: 0,
+        }
+    }
+
+    /// Returns the number of columns of the grid.
+    pub(crate) fn columns(&self) -> usize {
+        self.slots.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// Returns the number of columns covered by the row of the given index,
+    /// including the ones covered by cells spanning from the rows above.
+    pub(crate) fn row_columns(&self, row: usize) -> usize {
+        self.slots[row].len()
+    }
+
+    /// Returns the slots of the last row, to join a layout written after this
+    /// one. The cells are renumbered so that they never equal the cells of
+    /// another layout.
+    pub(crate) fn last_slots(&self) -> Option<Vec<Option<usize>>> {
+        let slots = self
+            .slots
+            .iter()
+            .rev()
+            .find(|s| s.iter().any(Option::is_some))?;
+        Some(slots.iter().map(|s| s.map(|i| usize::MAX - i)).collect())
+    }
+
+    /// Extends the last cell of every row covering fewer than `columns`
+    /// columns up to the last column. A cell spanning from a row above, or a
+    /// cell that would overlap another one, is left as is.
+    pub(crate) fn stretch(&mut self, columns: usize) {
+        for row in 0..self.slots.len() {
+            let end = self.slots[row].len();
+            let index = match self.slots[row].last() {
+                Some(&Some(i)) if end < columns && self.cells[i].row == row => i,
+                _ => continue,
+            };
+            let rows = row..row + self.cells[index].row_span;
+            let free = self.slots[rows.clone()]
+                .iter()
+                .all(|slots| slots.iter().skip(end).all(Option::is_none));
+            if !free {
+                continue;
+            }
+            for slots in &mut self.slots[rows] {
+                slots.resize(columns, None);
+                for slot in &mut slots[end..] {
+                    *slot = Some(index);
+                }
+            }
+            self.cells[index].col_span += columns - end;
+        }
+    }
+
+    /// Overrides the options of the cells by the ones returned by the `rules`,
+    /// the last rule taking precedence. `locations` are the section and the
+    /// index in it of each row. The column span of the cells is kept.
+    pub(crate) fn apply_rules(&mut self, rules: &[CellRule], locations: &[(Section, usize)]) {
+        if rules.is_empty() {
+            return;
+        }
+        let columns: Vec<Vec<Option<usize>>> = self
+            .slots
+            .iter()
+            .enumerate()
+            .map(|(row, slots)| {
+                slots
+                    .iter()
+                    .map(|slot| {
+                        let cell = &self.cells[(*slot)?];
+                        Some(cell.index).filter(|_| cell.row == row)
+                    })
+                    .collect()
+            })
+            .collect();
+        for cell in &mut self.cells {
+            let (section, row) = locations[cell.row];
+            let context = CellContext {
+                section,
+                row,
+                index: cell.index,
+                column: cell.column,
+                cells: &self.entries[cell.row].row.cells,
+                columns: &columns[cell.row],
+            };
+            let options = rules.iter().fold(Options::default(), |options, rule| {
+                rule(&context).or(&options)
+            });
+            cell.h_align = options.h_align.unwrap_or(cell.h_align);
+            cell.v_align = options.v_align.unwrap_or(cell.v_align);
+            cell.blank_char = options.blank_char.unwrap_or(cell.blank_char);
+            cell.padding = options.padding.unwrap_or(cell.padding);
+            cell.style = options.style.unwrap_or(cell.style);
+            if let Some(overflow) = options.overflow {
+                cell.overflow = Cow::Owned(overflow);
+            }
+        }
+    }
+
+    /// Returns `true` if a slot spanned by the cell of the given index is
+    /// covered by another cell.
+    pub(crate) fn overlaps(&self, index: usize) -> bool {
+        let cell = &self.cells[index];
+        (cell.row..cell.row + cell.row_span).any(|row| {
+            (cell.column..cell.column + cell.col_span)
+                .any(|column| self.slots[row][column] != Some(index))
+        })
+    }
+
+    /// Returns the starting column, the column span and the width of the
+    /// widest line of the content of every cell, its padding included.
+    pub(crate) fn spans(&self) -> Vec<(usize, usize, usize)> {
+        let decimals = decimal_widths(&self.cells, |c| match &c.cell.content {
+            CellContent::Text(content) => content.lines(),
+            CellContent::Grid(_) => "".lines(),
+        });
+        self.cells
+            .iter()
+            .map(|c| {
+                let width = match &c.cell.content {
+                    CellContent::Text(content) => {
+                        content.lines().map(text::width).max().unwrap_or(0)
+                    }
+                    CellContent::Grid(grid) => grid.nested_width(&c.options()),
+                };
+                let decimal = decimals
+                    .get(&(c.column, c.col_span))
+                    .map_or(0, |(int, frac)| int + frac);
+                let padding = c.padding.left + c.padding.right;
+                (c.column, c.col_span, width.max(decimal) + padding)
+            })
+            .collect()
+    }
+
+    /// Fits the content of the cells into the columns of the given `widths`
+    /// and computes the height of the rows.
+    ///
+    /// A row is as high as the tallest cell it contains. A cell spanning
+    /// multiple rows also covers the border lines between them, and if it is
+    /// still taller, the last row it spans grows to fit it.
+    pub(crate) fn arrange(
+        &mut self,
+        widths: Vec<usize>,
+        gutter_size: usize,
+        chars: Option<BorderChars>,
+        borders: Borders,
+    ) {
+        self.lines.clear();
+        self.continued.clear();
+        for cell in &mut self.cells {
+            cell.width = row::span_width(&widths, 0, gutter_size, cell.column, cell.col_span);
+            let start = self.lines.len();
+            match &cell.cell.content {
+                CellContent::Text(content) => {
+                    push_content_lines(
+                        &mut self.lines,
+                        &mut self.continued,
+                        content,
+                        &cell.overflow,
+                        cell.content_width(),
+                    );
+                }
+                CellContent::Grid(grid) => {
+                    let rendered =
+                        grid.render_nested(&cell.options(), cell.content_width(), self.color);
+                    let lines = rendered.lines().map(|l| Cow::Owned(l.to_owned()));
+                    self.lines.extend(lines);
+                }
+            }
+            self.continued.resize(self.lines.len(), false);
+            cell.lines = start..self.lines.len();
+        }
+        let lines = &self.lines;
+        let decimals = decimal_widths(&self.cells, |c| {
+            lines[c.lines.clone()].iter().map(|l| l.as_ref())
+        });
+        for cell in &mut self.cells {
+            if let Some(&parts) = decimals.get(&(cell.column, cell.col_span)) {
+                cell.decimal = parts;
+            }
+        }
+        // Rows covering no column are skipped, their separators going to the
+        // next row drawn. The separators left after the last row are drawn
+        // by the last rule, unless the bottom border already is.
+        self.rules.clear();
+        let mut drawn = self.previous.is_some();
+        let mut separators = Vec::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            separators.extend(entry.separator);
+            if self.is_empty_row(i) {
+                separators.extend(entry.separator_below);
+                self.rules.push(None);
+                continue;
+            }
+            let rule = Rule::new(chars, borders.rows, &separators);
+            self.rules.push(rule.filter(|_| drawn));
+            drawn = true;
+            separators.clear();
+            separators.extend(entry.separator_below);
+        }
+        let trailing = Rule::new(chars, false, &separators);
+        self.rules
+            .push(trailing.filter(|_| drawn && !(chars.is_some() && borders.bottom)));
+        let heights = &mut self.heights;
+        heights.iter_mut().for_each(|h| *h = 0);
+        for cell in self.cells.iter().filter(|c| c.row_span == 1) {
+            heights[cell.row] = heights[cell.row].max(cell.height());
+        }
+        let mut spanning = self
+            .cells
+            .iter()
+            .filter(|c| c.row_span > 1)
+            .collect::<Vec<_>>();
+        spanning.sort_by_key(|c| c.row_span);
+        for cell in spanning {
+            let last = cell.row + cell.row_span - 1;
+            let rules = self.rules[cell.row + 1..=last]
+                .iter()
+                .flatten()
+                .map(|r| r.height())
+                .sum::<usize>();
+            let height = heights[cell.row..=last].iter().sum::<usize>() + rules;
+            if cell.height() > height {
+                heights[last] += cell.height() - height;
+            }
+        }
+        let mut top = 0;
+        for (i, rule) in self.rules[..self.entries.len()].iter().enumerate() {
+            top += rule.map_or(0, Rule::height);
+            self.tops[i] = top;
+            top += self.heights[i];
+        }
+        self.widths = widths;
+        self.chars = chars;
+        self.borders = borders;
+        self.gutter_size = gutter_size;
+    }
+
+    /// Writes the arranged grid.
+    pub(crate) fn render(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        if self.widths.is_empty() {
+            return Ok(());
+        }
+        let rows = self.entries.len();
+        if self.borders.top {
+            let first = (0..rows).find(|&row| !self.is_empty_row(row));
+            self.write_rule(f, None, first)?;
+        }
+        for row in 0..rows {
+            self.write_separator(f, row)?;
+            for line in 0..self.heights[row] {
+                self.write_line(f, row, self.tops[row] + line)?;
+            }
+        }
+        self.write_separator(f, rows)?;
+        if self.borders.bottom {
+            self.write_rule(f, self.above(rows), None)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the rule above the row of the given index, or below the last
+    /// row if `row` is the number of rows.
+    fn write_separator(&self, f: &mut impl fmt::Write, row: usize) -> fmt::Result {
+        let width = self.widths.iter().sum::<usize>()
+            + self.gutter_size * self.widths.len().saturating_sub(1);
+        let chars = match self.rules[row] {
+            Some(Rule::Border) => {
+                let lower = Some(row).filter(|&row| row < self.entries.len());
+                return self.write_rule(f, self.above(row), lower);
+            }
+            Some(Rule::Line(c)) => [Some(c), None],
+            Some(Rule::Lines(c, d)) => [Some(c), Some(d)],
+            None => return Ok(()),
+        };
+        for c in chars.iter().flatten() {
+            write_repeated(f, *c, width)?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the first line, the number of chars preceding it
+    /// on its lines and the height of the arranged cell of the given index.
+    pub(crate) fn rect(&self, index: usize) -> (usize, usize, usize) {
+        let cell = &self.cells[index];
+        let border = |side: bool| usize::from(side && self.chars.is_some());
+        let last = cell.row + cell.row_span - 1;
+        let line = border(self.borders.top) + self.tops[cell.row];
+        let x = border(self.borders.left)
+            + self.widths.iter().take(cell.column).sum::<usize>()
+            + self.gutter_size * cell.column;
+        let height = self.tops[last] + self.heights[last] - self.tops[cell.row];
+        (line, x, height)
+    }
+
+    /// Returns the width of the widest line and the number of lines of the
+    /// arranged layout.
+    pub(crate) fn size(&self) -> (usize, usize) {
+        if self.widths.is_empty() {
+            return (0, 0);
+        }
+        let border = |side: bool| usize::from(side && self.chars.is_some());
+        let width = border(self.borders.left)
+            + self.widths.iter().sum::<usize>()
+            + self.gutter_size * self.widths.len().saturating_sub(1)
+            + border(self.borders.right);
+        let rows = self.tops.last().zip(self.heights.last());
+        let height = border(self.borders.top)
+            + rows.map_or(0, |(top, height)| top + height)
+            + self.rules.last().copied().flatten().map_or(0, Rule::height)
+            + border(self.borders.bottom);
+        (width, height)
+    }
+
+    /// Returns the row drawn above the row of the given index, skipping the
+    /// rows covering no column.
+    fn above(&self, row: usize) -> Option<Above> {
+        match (0..row).rev().find(|&row| !self.is_empty_row(row)) {
+            Some(row) => Some(Above::Row(row)),
+            None => self.previous.as_ref().map(|_| Above::Previous),
+        }
+    }
+
+    /// Returns `true` if no cell covers any column of the row.
+    fn is_empty_row(&self, row: usize) -> bool {
+        self.slots[row].iter().all(Option::is_none)
+    }
+
+    /// Returns the index of the cell covering the column of the row above a
+    /// border line, if any.
+    fn above_slot(&self, above: Option<Above>, column: usize) -> Option<usize> {
+        match above? {
+            Above::Row(row) => self.slot(Some(row), column),
+            Above::Previous => *self.previous.as_ref()?.get(column)?,
+        }
+    }
+
+    /// Returns the index of the cell covering the column of the row, if any.
+    fn slot(&self, row: Option<usize>, column: usize) -> Option<usize> {
+        *self.slots.get(row?)?.get(column)?
+    }
+
+    /// Returns the index of the cell starting at the column of the row, if any.
+    fn start(&self, row: usize, column: usize) -> Option<usize> {
+        self.slot(Some(row), column)
+            .filter(|&i| self.cells[i].column == column)
+    }
+
+    /// Writes the line `y` of the grid, which belongs to the given row.
+    fn write_line(&self, f: &mut impl fmt::Write, row: usize, y: usize) -> fmt::Result {
+        let vertical = self.chars.map(|c| c.vertical);
+        if let Some(c) = vertical.filter(|_| self.borders.left) {
+            f.write_char(c)?;
+        }
+        let end = if self.borders.right || self.pad {
+            self.widths.len()
+        } else {
+            self.slots[row]
+                .iter()
+                .rposition(Option::is_some)
+                .map_or(0, |i| i + 1)
+        };
+        let entry = &self.entries[row];
+        let blank_char = entry
+            .row
+            .default_options
+            .blank_char
+            .or(entry.stripe.and_then(|o| o.blank_char))
+            .or(entry.default_options.blank_char)
+            .unwrap_or(DEFAULT_BLANK_CHAR);
+        // The gutters and the uncovered columns of a striped row take the
+        // background of the stripe, so that it runs across the whole row.
+        let background = match entry.stripe.and_then(|o| o.style?.bg) {
+            Some(bg) if self.color => Style::PLAIN.bg(bg),
+            _ => Style::PLAIN,
+        };
+        let mut column = 0;
+        while column < end {
+            if column != 0 {
+                match vertical.filter(|_| self.borders.columns) {
+                    Some(c) if self.slot(Some(row), column - 1) != self.slot(Some(row), column) => {
+                        f.write_char(c)?
+                    }
+                    Some(_) => write_painted(f, background, blank_char, 1)?,
+                    None => write_painted(f, background, self.gutter_char, self.gutter_size)?,
+                }
+            }
+            match self.start(row, column) {
+                Some(i) => {
+                    self.write_cell(f, i, y)?;
+                    column += self.cells[i].col_span;
+                }
+                None => {
+                    write_painted(f, background, blank_char, self.widths[column])?;
+                    column += 1;
+                }
+            }
+        }
+        if let Some(c) = vertical.filter(|_| self.borders.right) {
+            f.write_char(c)?;
+        }
+        writeln!(f)
+    }
+
+    /// Writes the line `y` of the grid that falls into the cell of the given index.
+    fn write_cell(&self, f: &mut impl fmt::Write, index: usize, y: usize) -> fmt::Result {
+        let cell = &self.cells[index];
+        let top = self.tops[cell.row];
+        let last = cell.row + cell.row_span - 1;
+        let padding = cell.padding;
+        let height = (self.tops[last] + self.heights[last] - top)
+            .saturating_sub(padding.top + padding.bottom);
+        let index = (y - top)
+            .checked_sub(padding.top)
+            .filter(|&y| y < height)
+            .and_then(|y| row::aligned_index(cell.v_align, cell.lines.len(), height, y))
+            .map(|i| cell.lines.start + i);
+        let line = index.map_or("", |i| &self.lines[i]);
+        let width = cell.content_width();
+        let left = padding.left.min(cell.width);
+        let style = if self.color { cell.style } else { Style::PLAIN };
+        let mut p = Painter::new(f, style, cell.blank_char);
+        p.blanks(left)?;
+        match cell.h_align {
+            HAlign::Justify if index.is_some_and(|i| self.continued[i]) => {
+                row::write_justified(&mut p, line, width)
+            }
+            HAlign::Decimal(separator) if row::is_decimal(line) => {
+                row::write_decimal(&mut p, line, separator, cell.decimal, width)
+            }
+            h_align => row::write_padded(&mut p, h_align, line, width),
+        }?;
+        p.blanks(cell.width - left - width)?;
+        p.finish()
+    }
+
+    /// Writes a horizontal border line between the `upper` and the `lower`
+    /// rows. They are `None` for the top and the bottom borders.
+    fn write_rule(
+        &self,
+        f: &mut impl fmt::Write,
+        upper: Option<Above>,
+        lower: Option<usize>,
+    ) -> fmt::Result {
+        let chars = match self.chars {
+            Some(chars) => chars,
+            None => return Ok(()),
+        };
+        let spanning = |column: usize| match upper? {
+            Above::Row(row) => {
+                let index = self.start(row, column)?;
+                Some(index).filter(|&i| self.slot(lower, column) == Some(i))
+            }
+            Above::Previous => None,
+        };
+        let columns = self.widths.len();
+        let mut line = columns == 0 || spanning(0).is_none();
+        if self.borders.left {
+            f.write_char(chars.junction(upper.is_some(), lower.is_some(), false, line))?;
+        }
+        let mut column = 0;
+        while column < columns {
+            let cell = spanning(column);
+            if column != 0 {
+                let left = line;
+                line = cell.is_none();
+                if self.borders.columns {
+                    let up = self.above_slot(upper, column - 1) != self.above_slot(upper, column);
+                    let down = self.slot(lower, column - 1) != self.slot(lower, column);
+                    f.write_char(chars.junction(up, down, left, line))?;
+                } else {
+                    let c = if left || line {
+                        chars.horizontal
+                    } else {
+                        self.gutter_char
+                    };
+                    write_repeated(f, c, self.gutter_size)?;
+                }
+            }
+            match cell {
+                Some(i) => {
+                    self.write_cell(f, i, self.tops[lower.unwrap_or(0)] - 1)?;
+                    column += self.cells[i].col_span;
+                }
+                None => {
+                    write_repeated(f, chars.horizontal, self.widths[column])?;
+                    column += 1;
+                }
+            }
+        }
+        if self.borders.right {
+            f.write_char(chars.junction(upper.is_some(), lower.is_some(), line, false))?;
+        }
+        writeln!(f)
+    }
+}
+
+/// Returns the widths of the parts of the lines of the [`HAlign::Decimal`]
+/// cells before and from their separator, the widest among the cells covering
+/// the same columns, keyed by their starting column and their column span.
+/// Lines without any digit are left out.
+///
+/// [`HAlign::Decimal`]: enum.HAlign.html#variant.Decimal
+fn decimal_widths<'a, 'l, I>(
+    cells: &[Placement<'a>],
+    lines: impl Fn(&Placement<'a>) -> I,
+) -> HashMap<(usize, usize), (usize, usize)>
+where
+    I: Iterator<Item = &'l str>,
+{
+    let mut decimals = HashMap::new();
+    for cell in cells {
+        if let HAlign::Decimal(separator) = cell.h_align {
+            let parts = decimals
+                .entry((cell.column, cell.col_span))
+                .or_insert((0, 0));
+            for line in lines(cell).filter(|line| row::is_decimal(line)) {
+                let (int, frac) = row::decimal_widths(line, separator);
+                *parts = (parts.0.max(int), parts.1.max(frac));
+            }
+        }
+    }
+    decimals
+}
+
+/// Splits the content of a cell into lines fitting into `width` according to
+/// `overflow`, and appends them to `lines`. Empty content takes one empty line.
+/// The wrapped lines followed by another piece of the same line of content are
+/// marked in `continued`.
+fn push_content_lines<'a>(
+    lines: &mut Vec<Cow<'a, str>>,
+    continued: &mut Vec<bool>,
+    content: &'a str,
+    overflow: &Overflow,
+    width: usize,
+) {
+    let start = lines.len();
+    match overflow {
+        // Lines are truncated when they are written.
+        Overflow::Truncate => lines.extend(content.lines().map(Cow::Borrowed)),
+        Overflow::Wrap => {
+            for line in content.lines() {
+                let wrapped = text::wrap(line, width);
+                continued.resize(lines.len(), false);
+                continued.resize(lines.len() + wrapped.len() - 1, true);
+                lines.extend(wrapped);
+            }
+        }
+        Overflow::Ellipsis { marker, position } => lines.extend(
+            content
+                .lines()
+                .map(|l| text::ellipsize(l, width, marker, *position)),
+        ),
+    }
+    if lines.len() == start {
+        lines.push(Cow::Borrowed(""));
+    }
+}
+
+/// Writes `c` `count` times, a chunk of chars at a time.
+pub(crate) fn write_repeated(f: &mut impl fmt::Write, c: char, count: usize) -> fmt::Result {
+    let mut buf = [0; 64];
+    let len = c.len_utf8();
+    let chunk = count.min(buf.len() / len);
+    for i in 0..chunk {
+        c.encode_utf8(&mut buf[i * len..]);
+    }
+    let chunk = std::str::from_utf8(&buf[..chunk * len]).map_err(|_| fmt::Error)?;
+    let mut left = count;
+    while left > 0 {
+        let n = left.min(chunk.len() / len);
+        f.write_str(&chunk[..n * len])?;
+        left -= n;
+    }
+    Ok(())
+}
+
+/// Writes `c` `count` times in the given style.
+fn write_painted(f: &mut impl fmt::Write, style: Style, c: char, count: usize) -> fmt::Result {
+    let mut p = Painter::new(f, style, c);
+    p.blanks(count)?;
+    p.finish()
+}
+
+/// Returns the width of every column of the grid from the explicitly
+/// specified widths, falling back to `column_width` for the unlisted ones.
+pub(crate) fn column_widths(
+    columns: usize,
+    column_width: usize,
+    column_widths: &[usize],
+) -> Vec<usize> {
+    (0..columns)
+        .map(|i| column_widths.get(i).copied().unwrap_or(column_width))
+        .collect()
+}
+
+/// Computes the minimal width of each column so that the content of every
+/// cell fits into the columns it spans.
+///
+/// Cells spanning a single column are measured first. Then the multi column
+/// cells, narrowest span first, spread whatever width they still lack evenly
+/// across the columns they cover.
+///
+/// Each cell is given by its starting column, its column span and the width
+/// of its content.
+pub(crate) fn auto_column_widths(
+    spans: &[(usize, usize, usize)],
+    padding_size: usize,
+) -> Vec<usize> {
+    let mut spans = spans.to_vec();
+    let columns = spans
+        .iter()
+        .map(|&(start_column, col_span, _)| start_column + col_span)
+        .max()
+        .unwrap_or(0);
+    let mut widths = vec![0; columns];
+    spans.sort_by_key(|&(_, col_span, _)| col_span);
+    for (start_column, col_span, width) in spans {
+        if col_span == 0 {
+            continue;
+        }
+        let columns = &mut widths[start_column..start_column + col_span];
+        let current = columns.iter().sum::<usize>() + padding_size * (col_span - 1);
+        if width <= current {
+            continue;
+        }
+        let extra = width - current;
+        for (i, w) in columns.iter_mut().enumerate() {
+            *w += extra / col_span + usize::from(i < extra % col_span);
+        }
+    }
+    widths
+}
+
+/// Shrinks or grows the columns proportionally to their current `widths`, so
+/// that the whole grid, paddings included, takes `total_width` chars.
+///
+/// A column never gets narrower than its entry in `min_widths` (1 if not listed)
+/// nor wider than its entry in `max_widths` (unbounded if not listed). Columns
+/// hitting one of these bounds are frozen and the rest of the width is shared
+/// among the others. If the bounds can not be satisfied together with
+/// `total_width`, the bounds win.
+pub(crate) fn fit_column_widths(
+    widths: &[usize],
+    total_width: usize,
+    padding_size: usize,
+    min_widths: &[usize],
+    max_widths: &[usize],
+) -> Vec<usize> {
+    let columns = widths.len();
+    let target = total_width.saturating_sub(padding_size * columns.saturating_sub(1));
+    let min = |i: usize| min_widths.get(i).copied().unwrap_or(1);
+    let max = |i: usize| max_widths.get(i).copied().unwrap_or(usize::MAX).max(min(i));
+    let mut result = vec![0; columns];
+    let mut frozen = vec![false; columns];
+    loop {
+        let free = (0..columns).filter(|&i| !frozen[i]).collect::<Vec<_>>();
+        if free.is_empty() {
+            break;
+        }
+        let used = (0..columns)
+            .filter(|&i| frozen[i])
+            .map(|i| result[i])
+            .sum::<usize>();
+        let shares = share(
+            &free.iter().map(|&i| widths[i]).collect::<Vec<_>>(),
+            target.saturating_sub(used),
+        );
+        let mut violated = false;
+        for (&i, &share) in free.iter().zip(&shares) {
+            if share < min(i) {
+                result[i] = min(i);
+            } else if share > max(i) {
+                result[i] = max(i);
+            } else {
+                continue;
+            }
+            frozen[i] = true;
+            violated = true;
+        }
+        if !violated {
+            for (&i, share) in free.iter().zip(shares) {
+                result[i] = share;
+            }
+            break;
+        }
+    }
+    result
+}
+
+/// Splits `total` into parts proportional to `weights` using the largest
+/// remainder method, so that the parts always add up to `total`.
+fn share(weights: &[usize], total: usize) -> Vec<usize> {
+    let weights = if weights.iter().all(|&w| w == 0) {
+        vec![1; weights.len()]
+    } else {
+        weights.to_vec()
+    };
+    let weights_sum = weights.iter().sum::<usize>() as u128;
+    let mut parts = Vec::with_capacity(weights.len());
+    let mut remainders = Vec::with_capacity(weights.len());
+    for (i, &w) in weights.iter().enumerate() {
+        let exact = total as u128 * w as u128;
+        parts.push((exact / weights_sum) as usize);
+        remainders.push((exact % weights_sum, i));
+    }
+    let rest = total - parts.iter().sum::<usize>();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for &(_, i) in remainders.iter().take(rest) {
+        parts[i] += 1;
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout<'a>(rows: &'a [Row], options: &'a Options) -> Layout<'a> {
+        let entries = rows
+            .iter()
+            .map(|row| Entry {
+                row,
+                default_options: options,
+                separator: None,
+                separator_below: None,
+                section: 0,
+                stripe: None,
+            })
+            .collect();
+        Layout::new(entries, &[])
+    }
+
+    fn spans(rows: &[Row]) -> Vec<(usize, usize, usize)> {
+        layout(rows, &Options::default()).spans()
+    }
+
+    #[test]
+    fn test_auto_column_widths_single_spans() {
+        let rows = vec![
+            Row::new(vec![Cell::new("a".into(), 1), Cell::new("abc".into(), 1)]),
+            Row::new(vec![
+                Cell::new("ab".into(), 1),
+                Cell::new("a\nabcd".into(), 1),
+            ]),
+        ];
+        let result = auto_column_widths(&spans(&rows), 1);
+        let expected = vec![2, 4];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_auto_column_widths_spanned_cell_fits() {
+        let rows = vec![
+            Row::new(vec![Cell::new("abc".into(), 1), Cell::new("abc".into(), 1)]),
+            Row::new(vec![Cell::new("abcdefg".into(), 2)]),
+        ];
+        let result = auto_column_widths(&spans(&rows), 1);
+        let expected = vec![3, 3];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_auto_column_widths_spanned_cell_distributes_extra() {
+        let rows = vec![
+            Row::new(vec![Cell::new("a".into(), 1), Cell::new("a".into(), 1)]),
+            Row::new(vec![Cell::new("abcdefgh".into(), 2)]),
+        ];
+        let result = auto_column_widths(&spans(&rows), 1);
+        let expected = vec![4, 3];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_auto_column_widths_spanned_cell_only() {
+        let rows = vec![Row::new(vec![Cell::new("abcde".into(), 3)])];
+        let result = auto_column_widths(&spans(&rows), 0);
+        let expected = vec![2, 2, 1];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_column_widths_fallback() {
+        let rows = vec![
+            Row::new(vec![Cell::new("a".into(), 1), Cell::new("a".into(), 1)]),
+            Row::new(vec![Cell::new("a".into(), 3)]),
+        ];
+        let result = column_widths(layout(&rows, &Options::default()).columns(), 4, &[2]);
+        let expected = vec![2, 4, 4];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_layout_row_span_skips_covered_columns() {
+        let rows = vec![
+            Row::new(vec![
+                Cell::new("a".into(), 1),
+                Cell::builder("b".into(), 1).row_span(2).build(),
+                Cell::new("c".into(), 1),
+            ]),
+            Row::new(vec![Cell::new("d".into(), 1), Cell::new("e".into(), 1)]),
+        ];
+        let result = spans(&rows);
+        let expected = vec![(0, 1, 1), (1, 1, 1), (2, 1, 1), (0, 1, 1), (2, 1, 1)];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_layout_row_span_clamped_to_section() {
+        let rows = vec![Row::new(vec![Cell::builder("a".into(), 1)
+            .row_span(3)
+            .build()])];
+        let result = layout(&rows, &Options::default()).cells[0].row_span;
+        let expected = 1;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_arrange_row_span_grows_last_row() {
+        let rows = vec![
+            Row::new(vec![
+                Cell::builder("a\nb\nc\nd".into(), 1).row_span(2).build(),
+                Cell::new("e".into(), 1),
+            ]),
+            Row::new(vec![Cell::new("f".into(), 1)]),
+        ];
+        let options = Options::default();
+        let mut layout = layout(&rows, &options);
+        layout.arrange(vec![1, 1], 1, None, Borders::NONE);
+        let result = (layout.heights.clone(), layout.tops.clone());
+        let expected = (vec![1, 3], vec![0, 1]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_arrange_row_span_covers_border_lines() {
+        let rows = vec![
+            Row::new(vec![
+                Cell::builder("a\nb\nc".into(), 1).row_span(2).build(),
+                Cell::new("d".into(), 1),
+            ]),
+            Row::new(vec![Cell::new("e".into(), 1)]),
+        ];
+        let options = Options::default();
+        let mut layout = layout(&rows, &options);
+        let chars = crate::BorderStyle::Ascii.chars();
+        layout.arrange(vec![1, 1], 1, chars, Borders::ALL);
+        let result = (layout.heights.clone(), layout.tops.clone());
+        let expected = (vec![1, 1], vec![0, 2]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_column_options_between_row_and_defaults() {
+        let row = Row::builder(vec![
+            Cell::new("a".into(), 1),
+            Cell::new("b".into(), 1),
+            Cell::builder("c".into(), 1).v_align(VAlign::Bottom).build(),
+        ])
+        .default_blank_char('.')
+        .build();
+        let column = Options {
+            v_align: Some(VAlign::Middle),
+            blank_char: Some('-'),
+            ..Options::default()
+        };
+        let columns = vec![Options::default(), column.clone(), column];
+        let defaults = Options {
+            v_align: Some(VAlign::Bottom),
+            ..Options::default()
+        };
+        let entries = vec![Entry {
+            row: &row,
+            default_options: &defaults,
+            separator: None,
+            separator_below: None,
+            section: 0,
+            stripe: None,
+        }];
+        let layout = Layout::new(entries, &columns);
+        let result = layout
+            .cells
+            .iter()
+            .map(|c| (c.v_align, c.blank_char))
+            .collect::<Vec<_>>();
+        let expected = vec![
+            (VAlign::Bottom, '.'),
+            (VAlign::Middle, '.'),
+            (VAlign::Bottom, '.'),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_rect_and_size_with_borders() {
+        let rows = vec![
+            Row::new(vec![
+                Cell::builder("a\nb\nc".into(), 1).row_span(2).build(),
+                Cell::new("d".into(), 1),
+            ]),
+            Row::new(vec![Cell::new("e".into(), 1)]),
+        ];
+        let options = Options::default();
+        let mut layout = layout(&rows, &options);
+        let chars = crate::BorderStyle::Ascii.chars();
+        layout.arrange(vec![2, 3], 1, chars, Borders::ALL);
+        let result = (layout.rect(0), layout.rect(2), layout.size());
+        let expected = ((1, 1, 3), (3, 4, 1), (8, 5));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_padding_in_spans_and_heights() {
+        let padding = Padding {
+            left: 1,
+            right: 2,
+            top: 1,
+            bottom: 0,
+        };
+        let rows = vec![Row::new(vec![
+            Cell::builder("ab".into(), 1).padding(padding).build(),
+            Cell::new("c\nd".into(), 1),
+        ])];
+        let options = Options::default();
+        let mut layout = layout(&rows, &options);
+        let spans = layout.spans();
+        layout.arrange(vec![5, 1], 1, None, Borders::NONE);
+        let result = (spans, layout.heights.clone());
+        let expected = (vec![(0, 1, 5), (1, 1, 1)], vec![2]);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_layout_stretch_last_cell() {
+        let rows = vec![
+            Row::new(vec![
+                Cell::new("a".into(), 1),
+                Cell::new("b".into(), 1),
+                Cell::new("c".into(), 1),
+            ]),
+            Row::new(vec![Cell::new("d".into(), 1), Cell::new("e".into(), 1)]),
+        ];
+        let options = Options::default();
+        let mut layout = layout(&rows, &options);
+        layout.stretch(layout.columns());
+        let result = layout
+            .cells
+            .iter()
+            .map(|c| (c.column, c.col_span))
+            .collect::<Vec<_>>();
+        let expected = vec![(0, 1), (1, 1), (2, 1), (0, 1), (1, 2)];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_layout_stretch_skips_cell_spanning_from_above() {
+        let rows = vec![
+            Row::new(vec![
+                Cell::new("a".into(), 1),
+                Cell::builder("b".into(), 1).row_span(2).build(),
+                Cell::new("c".into(), 1),
+            ]),
+            Row::new(vec![Cell::new("d".into(), 1)]),
+        ];
+        let options = Options::default();
+        let mut layout = layout(&rows, &options);
+        layout.stretch(layout.columns());
+        let result = (layout.row_columns(1), layout.cells[1].col_span);
+        let expected = (2, 1);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_share_proportional() {
+        let result = share(&[1, 2, 1], 10);
+        let expected = vec![3, 5, 2];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_share_zero_weights() {
+        let result = share(&[0, 0, 0], 4);
+        let expected = vec![2, 1, 1];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_fit_column_widths_shrink() {
+        let result = fit_column_widths(&[10, 30], 22, 2, &[], &[]);
+        let expected = vec![5, 15];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_fit_column_widths_grow() {
+        let result = fit_column_widths(&[2, 4], 13, 1, &[], &[]);
+        let expected = vec![4, 8];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_fit_column_widths_min() {
+        let result = fit_column_widths(&[2, 38], 21, 1, &[5], &[]);
+        let expected = vec![5, 15];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_fit_column_widths_max() {
+        let result = fit_column_widths(&[10, 10], 31, 1, &[], &[10]);
+        let expected = vec![10, 20];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_fit_column_widths_too_narrow() {
+        let result = fit_column_widths(&[10, 10, 10], 2, 1, &[], &[]);
+        let expected = vec![1, 1, 1];
+        assert_eq!(result, expected);
+    }
+}
@@ -1,5 +1,5 @@
-//! A small and dependency free crate for formatting the terminal outputs
-//! in a column based grid style.
+//! A small crate with no required dependencies for formatting the terminal
+//! outputs in a column based grid style.
 //! ```text
 //! [---------------------]     [---------------------]     [---------------------]
 //! [---------------------]     [---------------------]     [---------------------]
@@ -16,6 +16,59 @@
 //! [---------------------------- Cell with colspan 3 ----------------------------]
 //! ```
 //!
+//! Columns share the same [`column_width`] by default, but each column can
+//! be given its own width by [`column_widths`].
+//!
+//! [`column_width`]: struct.GridBuilder.html#method.column_width
+//! [`column_widths`]: struct.GridBuilder.html#method.column_widths
+//!
+//! Default options can be given per column too by [`column_options`], e.g. to
+//! line up a column of numbers on their decimal point with [`HAlign::Decimal`].
+//! A cell uses the options of the column it starts in where neither the cell
+//! nor its row specifies them.
+//!
+//! [`column_options`]: struct.GridBuilder.html#method.column_options
+//! [`HAlign::Decimal`]: enum.HAlign.html#variant.Decimal
+//!
+//! With [`auto_size`] the widths are computed from the content of the cells instead:
+//! ```rust
+//! use cli_grid::*;
+//!
+//! let grid = Grid::builder(vec![
+//!     Row::new(vec![
+//!         Cell::new("id".into(), 1),
+//!         Cell::new("description".into(), 1),
+//!     ]),
+//!     Row::new(vec![
+//!         Cell::new("1".into(), 1),
+//!         Cell::new("first".into(), 1),
+//!     ]),
+//! ])
+//! .default_blank_char('.')
+//! .auto_size(true)
+//! .build();
+//!
+//! let expected = format!(
+//!     "{}\n{}\n",
+//!     "id description",
+//!     "1. first......",
+//! );
+//!
+//! assert_eq!(grid.to_string(), expected);
+//! ```
+//!
+//! [`auto_size`]: struct.GridBuilder.html#method.auto_size
+//!
+//! The grid can also be fitted into a total width by [`total_width`], or into
+//! the width of the terminal by [`fit_terminal`]. The columns are shrunk or grown
+//! proportionally, within the bounds given by [`min_column_widths`] and
+//! [`max_column_widths`].
+//!
+//! [`total_width`]: struct.GridBuilder.html#method.total_width
+//! [`fit_terminal`]: struct.GridBuilder.html#method.fit_terminal
+//! [`min_column_widths`]: struct.GridBuilder.html#method.min_column_widths
+//! [`max_column_widths`]: struct.GridBuilder.html#method.max_column_widths
+//!
 //! Horizontal alignments of cells are [`HAlign::Left`], [`HAlign::Center`],
 //! [`HAlign::Right`] and [`HAlign::Fill`],
 //!
@@ -149,7 +202,274 @@
 //!
 //! assert_eq!(grid.to_string(), expected);
 //! ```
-//! 
+//!
+//! Formatting the nested grid into a string gives it a fixed width. Held by
+//! [`Cell::new_grid`] instead, it is laid out against the width of its cell, and
+//! takes the options of the cell where it leaves its own unset:
+//! ```rust
+//! use cli_grid::*;
+//!
+//! let nested_grid = Grid::builder(vec![
+//!     Row::new(vec![
+//!         Cell::new("1".into(), 1),
+//!         Cell::new("1".into(), 1),
+//!     ]),
+//!     Row::new(vec![
+//!         Cell::new("1".into(), 1),
+//!         Cell::new("1".into(), 1),
+//!     ]),
+//! ])
+//! .column_width(5)
+//! .build();
+//!
+//! let grid = Grid::builder(vec![
+//!     Row::new(vec![
+//!         Cell::new("2".into(), 2),
+//!         Cell::new("1".into(), 1),
+//!     ]),
+//!     Row::new(vec![
+//!         Cell::new("1".into(), 1),
+//!         Cell::new_grid(nested_grid, 1),
+//!         Cell::new("1".into(), 1),
+//!     ]),
+//!     Row::new(vec![
+//!         Cell::new("3".into(), 3),
+//!     ]),
+//! ])
+//! .default_h_align(HAlign::Center)
+//! .default_v_align(VAlign::Middle)
+//! .default_blank_char('.')
+//! .column_width(15)
+//! .build();
+//!
+//! let expected = format!(
+//!     "{}\n{}\n{}\n{}\n",
+//!     "...............2............... .......1.......",
+//!     ".......1....... ...1... ...1... .......1.......",
+//!     "............... ...1... ...1... ...............",
+//!     ".......................3.......................",
+//! );
+//!
+//! assert_eq!(grid.to_string(), expected);
+//! ```
+//!
+//! [`Cell::new_grid`]: struct.Cell.html#method.new_grid
+//!
+//! Lines wider than their cell are truncated by default. With [`Overflow::Wrap`]
+//! they are wrapped on word boundaries into additional lines instead:
+//! ```rust
+//! use cli_grid::*;
+//!
+//! let grid = Grid::builder(vec![
+//!     Row::new(vec![
+//!         Cell::new("1".into(), 1),
+//!         Cell::new("a long description".into(), 1),
+//!     ]),
+//! ])
+//! .default_blank_char('.')
+//! .default_overflow(Overflow::Wrap)
+//! .column_widths(vec![1, 8])
+//! .build();
+//!
+//! let expected = format!(
+//!     "{}\n{}\n{}\n",
+//!     "1 a long..",
+//!     ". descript",
+//!     ". ion.....",
+//! );
+//!
+//! assert_eq!(grid.to_string(), expected);
+//! ```
+//!
+//! [`Overflow::Wrap`]: enum.Overflow.html#variant.Wrap
+//!
+//! Wrapped paragraphs can be justified by [`HAlign::Justify`], which spreads
+//! blanks between the words of every line but the last of each paragraph.
+//!
+//! [`HAlign::Justify`]: enum.HAlign.html#variant.Justify
+//!
+//! [`Overflow::Ellipsis`] truncates the lines too, but marks the cut by a marker
+//! string at the start, the middle or the end of the line.
+//!
+//! [`Overflow::Ellipsis`]: enum.Overflow.html#variant.Ellipsis
+//!
+//! By default each char of the content is taken as 1 column wide. Enable the
+//! `unicode` feature to measure the content by its display width in the terminal
+//! instead, so that East Asian wide chars, emoji and combining marks are aligned
+//! correctly. Truncation never splits a grapheme cluster or a double width glyph.
+//!
+//! ANSI escape sequences in the content, like SGR colours and OSC 8 hyperlinks,
+//! take no space. When a styled line is truncated, its styles are reset at the cut.
+//!
+//! Rather than writing escape sequences into the content, the cells can be
+//! given colours and text attributes by [`Style`], set per cell by
+//! [`CellBuilder::style`] or for all the cells by [`default_style`]. The text
+//! and the blanks around it are styled separately, the blanks only taking the
+//! colours:
+//! ```rust
+//! use cli_grid::*;
+//!
+//! let grid = Grid::builder(vec![Row::new(vec![Cell::new("1".into(), 1)])])
+//!     .default_style(Style::PLAIN.fg(Color::Red).underline())
+//!     .column_width(2)
+//!     .build();
+//!
+//! assert_eq!(grid.to_string(), "\x1b[4;31m1\x1b[0m\x1b[31m \x1b[0m\n");
+//! ```
+//!
+//! [`Style`]: struct.Style.html
+//! [`CellBuilder::style`]: struct.CellBuilder.html#method.style
+//! [`default_style`]: struct.GridBuilder.html#method.default_style
+//!
+//! The styles are always written by the [`Display`] of the grid. To follow a
+//! colour policy instead, e.g. from a `--color` command line option, render
+//! the grid through [`Grid::display`] with a [`RenderConfig`]. By default it
+//! only writes the styles when the standard output is a terminal and the
+//! `NO_COLOR` environment variable is not set.
+//!
+//! [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+//! [`Grid::display`]: struct.Grid.html#method.display
+//! [`RenderConfig`]: struct.RenderConfig.html
+//!
+//! To help following long rows, every other stripe of [`stripe_size`] rows
+//! can be given its own options by [`stripe_options`], e.g. a background
+//! colour or a blank char. They apply under the options of the rows and the
+//! cells themselves, and a row of many lines counts as a single row.
+//!
+//! [`stripe_size`]: struct.GridBuilder.html#method.stripe_size
+//! [`stripe_options`]: struct.GridBuilder.html#method.stripe_options
+//!
+//! Options depending on the content can be set by rules, closures given to
+//! [`GridBuilder::rule`] that are evaluated for each cell when the grid is
+//! rendered. A rule sees the position of the cell and the content of its row
+//! through a [`CellContext`], and returns the options overriding the cell's.
+//!
+//! [`GridBuilder::rule`]: struct.GridBuilder.html#method.rule
+//! [`CellContext`]: struct.CellContext.html
+//!
+//! Borders can be drawn around and between the cells by [`border_style`]. The
+//! junctions are joined correctly around cells with different column spans:
+//! ```rust
+//! use cli_grid::*;
+//!
+//! let grid = Grid::builder(vec![
+//!     Row::new(vec![
+//!         Cell::new("1".into(), 1),
+//!         Cell::new("1".into(), 1),
+//!         Cell::new("1".into(), 1),
+//!     ]),
+//!     Row::new(vec![
+//!         Cell::new("2".into(), 2),
+//!         Cell::new("1".into(), 1),
+//!     ]),
+//! ])
+//! .border_style(BorderStyle::Light)
+//! .column_width(3)
+//! .build();
+//!
+//! let expected = format!(
+//!     "{}\n{}\n{}\n{}\n{}\n",
+//!     "┌───┬───┬───┐",
+//!     "│1  │1  │1  │",
+//!     "├───┴───┼───┤",
+//!     "│2      │1  │",
+//!     "└───────┴───┘",
+//! );
+//!
+//! assert_eq!(grid.to_string(), expected);
+//! ```
+//!
+//! [`border_style`]: struct.GridBuilder.html#method.border_style
+//!
+//! The borders, the spacing and the default options can also be set at once by
+//! a [`Theme`], either a built-in one like [`Theme::rounded`] or [`Theme::markdown`],
+//! or one shared by the tools of a project. [`GridBuilder::theme`] applies it
+//! wholesale, and the methods called afterwards override it piecemeal.
+//!
+//! [`Theme`]: struct.Theme.html
+//! [`Theme::rounded`]: struct.Theme.html#method.rounded
+//! [`Theme::markdown`]: struct.Theme.html#method.markdown
+//! [`GridBuilder::theme`]: struct.GridBuilder.html#method.theme
+//!
+//! To keep the content off the borders, the cells can be padded on each side
+//! by [`Padding`], set per cell by [`CellBuilder::padding`] or for all the
+//! cells by [`default_padding`]. The gutters between the columns without a
+//! column border are filled by [`gutter_char`]:
+//! ```rust
+//! use cli_grid::*;
+//!
+//! let grid = Grid::builder(vec![Row::new(vec![
+//!     Cell::new("1".into(), 1),
+//!     Cell::new("22".into(), 1),
+//! ])])
+//! .border_style(BorderStyle::Ascii)
+//! .borders(Borders::FRAME)
+//! .default_padding(Padding::horizontal(1))
+//! .gutter_char('.')
+//! .auto_size(true)
+//! .build();
+//!
+//! let expected = format!(
+//!     "{}\n{}\n{}\n",
+//!     "+--------+",
+//!     "| 1 . 22 |",
+//!     "+--------+",
+//! );
+//!
+//! assert_eq!(grid.to_string(), expected);
+//! ```
+//!
+//! [`Padding`]: struct.Padding.html
+//! [`CellBuilder::padding`]: struct.CellBuilder.html#method.padding
+//! [`default_padding`]: struct.GridBuilder.html#method.default_padding
+//! [`gutter_char`]: struct.GridBuilder.html#method.gutter_char
+//!
+//! Header and footer rows are kept apart from the other rows by [`header`] and
+//! [`footer`], with their own default options and optional separator lines. The
+//! header can be repeated at the start of every page of rows by [`page_size`].
+//!
+//! [`header`]: struct.GridBuilder.html#method.header
+//! [`footer`]: struct.GridBuilder.html#method.footer
+//! [`page_size`]: struct.GridBuilder.html#method.page_size
+//!
+//! A cell can also span several rows by [`row_span`]. The cells of the rows
+//! below it are placed after the columns it covers, and its content is aligned
+//! vertically over the combined height of the spanned rows.
+//!
+//! [`row_span`]: struct.CellBuilder.html#method.row_span
+//!
+//! Besides [`Display`], a grid can be written into an [`io::Write`] by
+//! [`write_to`]. To write a large number of rows without keeping them all in
+//! memory, [`write_rows_to`] and [`writer`] lay out and flush the rows as they
+//! come, at the cost of computing the column widths from the header and the
+//! footer only.
+//!
+//! [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+//! [`io::Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+//! [`write_to`]: struct.Grid.html#method.write_to
+//! [`write_rows_to`]: struct.Grid.html#method.write_rows_to
+//! [`writer`]: struct.Grid.html#method.writer
+//!
+//! [`Cell::new`] panics on a column span of `0`. To validate a grid built from
+//! user input instead, use [`Cell::try_new`] and [`GridBuilder::try_build`], which
+//! return a [`GridError`] for invalid spans and column widths.
+//!
+//! [`Cell::new`]: struct.Cell.html#method.new
+//! [`Cell::try_new`]: struct.Cell.html#method.try_new
+//! [`GridBuilder::try_build`]: struct.GridBuilder.html#method.try_build
+//! [`GridError`]: enum.GridError.html
+//!
+//! Rows covering fewer columns than the others are reported by
+//! [`GridBuilder::try_build`], or normalised by the [`ragged_rows`] policy.
+//!
+//! [`ragged_rows`]: struct.GridBuilder.html#method.ragged_rows
+//!
+//! Where the cells end up in the rendered grid is given by [`Grid::layout`]: the
+//! column widths, and the line, offset and size of every cell along with its
+//! resolved alignments and blank char.
+//!
+//! [`Grid::layout`]: struct.Grid.html#method.layout
+//!
 //! Empty cells and rows can be created by [`Cell::new_empty`] and [`Row::new_empty`] methods.
 //!
 //! [`Cell::new_empty`]: struct.Cell.html#method.new_empty
@@ -161,12 +481,33 @@
 //! [`Row::new_fill`]: struct.Row.html#method.new_fill
 //!
 
+mod border;
+mod error;
 mod grid;
+mod layout;
 mod row;
 mod cell;
 mod options;
+mod render;
+mod rule;
+mod stream;
+mod style;
+mod terminal;
+mod theme;
+mod text;
 
-pub use cell::{Cell, CellBuilder, HAlign, VAlign};
-pub use grid::{Grid, GridBuilder};
+pub use border::{BorderChars, BorderStyle, Borders};
+pub use error::{GridError, Location, Section};
+pub use cell::{
+    Cell, CellBuilder, CellContent, EllipsisPosition, HAlign, Overflow, Padding, VAlign,
+};
+pub use grid::{Grid, GridBuilder, RaggedRows};
+pub use layout::{CellLayout, GridLayout};
 pub use row::{Row, RowBuilder};
 pub use options::Options;
+pub use render::{ColorChoice, GridDisplay, RenderConfig};
+pub use rule::{CellContext, CellRule};
+pub use stream::GridWriter;
+pub use style::{Color, Style};
+pub use terminal::terminal_width;
+pub use theme::Theme;
@@ -1,6 +1,7 @@
-use crate::{HAlign, VAlign};
+use crate::{HAlign, Overflow, Padding, Style, VAlign};
 
 /// Options for the grid system.
+#[derive(Clone, Debug, Default)]
 pub struct Options {
     /// Default column span for all the cells of the grid. If a cell specifies
     /// a column span it will be used instead of the grids default value.
@@ -17,4 +18,32 @@ pub struct Options {
     /// Default blank char for all the cells of the grid. If a cell specifies
     /// a blank char it will be used instead of the grids default value.
     pub blank_char: Option<char>,
+
+    /// Default overflow behaviour for all the cells of the grid. If a cell specifies
+    /// an overflow behaviour it will be used instead of the grids default value.
+    pub overflow: Option<Overflow>,
+
+    /// Default padding for all the cells of the grid. If a cell specifies
+    /// a padding it will be used instead of the grids default value.
+    pub padding: Option<Padding>,
+
+    /// Default style for all the cells of the grid. If a cell specifies
+    /// a style it will be used instead of the grids default value.
+    pub style: Option<Style>,
+}
+
+impl Options {
+    /// Returns the options specified by `self`, falling back to the ones
+    /// specified by `other` for the rest.
+    pub(crate) fn or(&self, other: &Options) -> Options {
+        Options {
+            col_span: self.col_span.or(other.col_span),
+            h_align: self.h_align.or(other.h_align),
+            v_align: self.v_align.or(other.v_align),
+            blank_char: self.blank_char.or(other.blank_char),
+            overflow: self.overflow.clone().or_else(|| other.overflow.clone()),
+            padding: self.padding.or(other.padding),
+            style: self.style.or(other.style),
+        }
+    }
 }
@@ -0,0 +1,150 @@
+use crate::{
+    grid::Grid,
+    row::Row,
+    stream::{GridWriter, IoWriter},
+};
+
+use std::{
+    ffi::OsStr,
+    fmt,
+    io::{self, IsTerminal},
+};
+
+/// Whether the styles of the cells are written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Writes the styles if the standard output is a terminal and the
+    /// `NO_COLOR` environment variable is not set to a non empty value. (default)
+    #[default]
+    Auto,
+
+    /// Always writes the styles.
+    Always,
+
+    /// Never writes the styles.
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns `true` if the styles are to be written.
+    fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => auto_enabled(
+                std::env::var_os("NO_COLOR").as_deref(),
+                io::stdout().is_terminal(),
+            ),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+/// Returns `true` if [`ColorChoice::Auto`] writes the styles, given the value
+/// of the `NO_COLOR` environment variable and whether the standard output is
+/// a terminal.
+///
+/// [`ColorChoice::Auto`]: enum.ColorChoice.html#variant.Auto
+fn auto_enabled(no_color: Option<&OsStr>, is_terminal: bool) -> bool {
+    match no_color {
+        Some(no_color) if !no_color.is_empty() => false,
+        _ => is_terminal,
+    }
+}
+
+/// Options of the rendering of a [`Grid`], kept apart from the grid so that
+/// the same grid can be rendered differently without being rebuilt.
+///
+/// [`Grid`]: struct.Grid.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RenderConfig {
+    /// Whether the [`Style`] of the cells is written. Escape sequences written
+    /// into the content of the cells are kept in any case.
+    ///
+    /// [`Style`]: struct.Style.html
+    pub color: ColorChoice,
+}
+
+impl RenderConfig {
+    /// Writes the styles of the cells in any case, like the [`Display`] of
+    /// [`Grid`] does.
+    ///
+    /// [`Display`]: https://doc.rust-lang.org/std/fmt/trait.Display.html
+    /// [`Grid`]: struct.Grid.html
+    pub const ALWAYS: RenderConfig = RenderConfig {
+        color: ColorChoice::Always,
+    };
+}
+
+/// A [`Grid`] along with a [`RenderConfig`], created by [`Grid::display`].
+///
+/// [`Grid`]: struct.Grid.html
+/// [`RenderConfig`]: struct.RenderConfig.html
+/// [`Grid::display`]: struct.Grid.html#method.display
+#[derive(Debug)]
+pub struct GridDisplay<'a> {
+    grid: &'a Grid,
+    config: RenderConfig,
+}
+
+impl<'a> GridDisplay<'a> {
+    pub(crate) fn new(grid: &'a Grid, config: RenderConfig) -> Self {
+        Self { grid, config }
+    }
+
+    /// Writes the grid into `writer`, like [`Grid::write_to`].
+    ///
+    /// [`Grid::write_to`]: struct.Grid.html#method.write_to
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut writer = IoWriter::new(writer);
+        let result = self.grid.render(&mut writer, self.config.color.enabled());
+        writer.result(result)
+    }
+
+    /// Creates a [`GridWriter`] that writes the grid into `writer` part by
+    /// part, like [`Grid::writer`].
+    ///
+    /// [`GridWriter`]: struct.GridWriter.html
+    /// [`Grid::writer`]: struct.Grid.html#method.writer
+    pub fn writer<W: io::Write>(&self, writer: W) -> io::Result<GridWriter<'a, W>> {
+        GridWriter::new(self.grid, writer, self.config.color.enabled())
+    }
+
+    /// Writes the grid into `writer` followed by the `rows`, like
+    /// [`Grid::write_rows_to`].
+    ///
+    /// [`Grid::write_rows_to`]: struct.Grid.html#method.write_rows_to
+    pub fn write_rows_to<I, W>(&self, rows: I, writer: W) -> io::Result<()>
+    where
+        I: IntoIterator<Item = Row>,
+        W: io::Write,
+    {
+        let mut writer = self.writer(writer)?;
+        for row in rows {
+            writer.write_row(row)?;
+        }
+        writer.finish().map(|_| ())
+    }
+}
+
+impl fmt::Display for GridDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.render(f, self.config.color.enabled())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_choice_auto() {
+        let result = [
+            auto_enabled(None, true),
+            auto_enabled(Some(OsStr::new("")), true),
+            auto_enabled(Some(OsStr::new("1")), true),
+            auto_enabled(None, false),
+        ];
+        let expected = [true, true, false, false];
+        assert_eq!(result, expected);
+    }
+}
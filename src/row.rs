@@ -1,13 +1,19 @@
 use crate::{
-    cell::{Cell, HAlign, VAlign, DEFAULT_BLANK_CHAR, DEFAULT_H_ALIGN, DEFAULT_V_ALIGN},
+    border::Borders,
+    cell::{Cell, HAlign, Overflow, Padding, VAlign, DEFAULT_BLANK_CHAR},
+    error::{GridError, Location, Section},
+    layout::{self, Entry, Layout},
     options::Options,
+    style::{Painter, Style},
+    text,
 };
 
-use std::borrow::Cow;
+use std::fmt;
 
 /// Data type for creating a [`Row`] for the grid.
 ///
 /// [`Row`]: struct.Row.html
+#[derive(Debug)]
 pub struct Row {
     /// These options will be used if the equivalent is not provided
     /// by the underlying [`Cell`] type.
@@ -15,14 +21,43 @@ pub struct Row {
     /// [`Cell`]: struct.Cell.html
     pub default_options: Options,
 
-    /// Width in chars for each column of the [`Row`].
+    /// Width in chars for each column of the [`Row`]. Inside a [`Grid`] it
+    /// applies to all the rows if the grid leaves its own unset, and the other
+    /// rows setting it must agree, see [`Grid::validate`].
     ///
     /// [`Row`]: struct.Row.html
+    /// [`Grid`]: struct.Grid.html
+    /// [`Grid::validate`]: struct.Grid.html#method.validate
     pub column_width: Option<usize>,
 
+    /// Width in chars for each individual column of the [`Row`]. Columns
+    /// without an entry fall back to [`column_width`]. Inside a [`Grid`] they
+    /// apply to all the rows if the grid leaves its own unset, and the other
+    /// rows setting them must agree, see [`Grid::validate`].
+    ///
+    /// [`Row`]: struct.Row.html
+    /// [`column_width`]: struct.Row.html#structfield.column_width
+    /// [`Grid`]: struct.Grid.html
+    /// [`Grid::validate`]: struct.Grid.html#method.validate
+    pub column_widths: Option<Vec<usize>>,
+
     /// Number of char spaces for each padding space between row columns.
+    /// Inside a [`Grid`] it applies to all the rows if the grid leaves its own
+    /// unset, and the other rows setting it must agree, see [`Grid::validate`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Grid::validate`]: struct.Grid.html#method.validate
     pub padding_size: Option<usize>,
 
+    /// Char filling the padding spaces between row columns. If `None`
+    /// specified, white space char (`'\x20'`) will be used. Inside a [`Grid`]
+    /// it applies to all the rows if the grid leaves its own unset, and the
+    /// other rows setting it must agree, see [`Grid::validate`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Grid::validate`]: struct.Grid.html#method.validate
+    pub gutter_char: Option<char>,
+
     /// Collection of cells that this [`Row`] contains.
     ///
     /// [`Row`]: struct.Row.html
@@ -35,14 +70,11 @@ impl Row {
     /// [`Row`]: struct.Row.html
     pub fn new(cells: Vec<Cell>) -> Self {
         Self {
-            default_options: Options {
-                col_span: None,
-                h_align: None,
-                v_align: None,
-                blank_char: None,
-            },
+            default_options: Options::default(),
             column_width: None,
+            column_widths: None,
             padding_size: None,
+            gutter_char: None,
             cells,
         }
     }
@@ -70,7 +102,13 @@ impl Row {
 
     /// Formats the [`Row`] into a string.
     ///
+    /// The width of each column is taken from the [`column_widths`] of the row,
+    /// falling back to `column_width` for the columns that are not listed. A cell spanning
+    /// multiple columns takes the sum of their widths plus the paddings between them.
+    /// Row spans have no effect on a row rendered on its own.
+    ///
     /// [`Row`]: struct.Row.html
+    /// [`column_widths`]: struct.Row.html#structfield.column_widths
     pub fn render(
         &self,
         f: &mut std::fmt::Formatter<'_>,
@@ -79,59 +117,32 @@ impl Row {
         padding_size: Option<usize>,
     ) -> std::fmt::Result {
         let column_width = column_width.or(self.column_width).unwrap_or(1);
+        let column_widths = self.column_widths.as_deref().unwrap_or(&[]);
         let padding_size = padding_size.or(self.padding_size).unwrap_or(1);
-        let mut cols_lines = self
-            .cells
-            .iter()
-            .map(|c| {
-                let mut lines = c.content.lines().map(|l| l.to_owned()).collect::<Vec<_>>();
-                if lines.is_empty() {
-                    lines.push(String::new());
-                }
-                lines
-            })
-            .collect::<Vec<_>>();
-        let max_lines = cols_lines
-            .iter()
-            .map(|col_lines| col_lines.len())
-            .max()
-            .unwrap_or(0);
-        for line_index in 0..max_lines {
-            for (col_index, col) in self.cells.iter().enumerate() {
-                let col_lines = &mut cols_lines[col_index];
-                let col_span = col
-                    .col_span
-                    .or(self.default_options.col_span)
-                    .or(default_options.col_span)
-                    .unwrap_or(1);
-                let col_width = col_span * column_width + padding_size * (col_span - 1);
-                let h_align = col
-                    .h_align
-                    .or(self.default_options.h_align)
-                    .or(default_options.h_align)
-                    .unwrap_or(DEFAULT_H_ALIGN);
-                let v_align = col
-                    .v_align
-                    .or(self.default_options.v_align)
-                    .or(default_options.v_align)
-                    .unwrap_or(DEFAULT_V_ALIGN);
-                let blank_char = col
-                    .blank_char
-                    .or(self.default_options.blank_char)
-                    .or(default_options.blank_char)
-                    .unwrap_or(DEFAULT_BLANK_CHAR);
-                if col_index != 0 {
-                    write!(f, "{s:<0$}", padding_size, s = "")?;
-                }
-                write!(
-                    f,
-                    "{}",
-                    col_line(
-                        h_align, v_align, col_width, col_lines, max_lines, line_index, blank_char,
-                    )
-                )?;
-            }
-            writeln!(f)?;
+        let entries = vec![Entry {
+            row: self,
+            default_options,
+            separator: None,
+            separator_below: None,
+            section: 0,
+            stripe: None,
+        }];
+        let mut layout = Layout::new(entries, &[]);
+        layout.gutter_char = self.gutter_char.unwrap_or(DEFAULT_BLANK_CHAR);
+        let widths = layout::column_widths(layout.columns(), column_width, column_widths);
+        layout.arrange(widths, padding_size, None, Borders::NONE);
+        layout.render(f)
+    }
+
+    /// Checks that the default column span of the row and the spans of its
+    /// cells are not `0`. `section` and `row` locate the row in its grid.
+    pub(crate) fn validate(&self, section: Section, row: usize) -> Result<(), GridError> {
+        if self.default_options.col_span == Some(0) {
+            return Err(GridError::ZeroColSpan(Location::Row(section, row)));
+        }
+        for (i, cell) in self.cells.iter().enumerate() {
+            cell.validate()
+                .map_err(|e| e.at(Location::Cell(section, row, i)))?;
         }
         Ok(())
     }
@@ -148,98 +159,143 @@ impl std::fmt::Display for Row {
     }
 }
 
-fn col_line(
-    h_align: HAlign,
+pub(crate) fn span_width(
+    column_widths: &[usize],
+    column_width: usize,
+    padding_size: usize,
+    start_column: usize,
+    col_span: usize,
+) -> usize {
+    let widths = (start_column..start_column + col_span)
+        .map(|i| column_widths.get(i).copied().unwrap_or(column_width))
+        .sum::<usize>();
+    widths + padding_size * col_span.saturating_sub(1)
+}
+
+/// Returns the index of the line of a cell shown on the line `line_index` of a
+/// column of `max_lines` lines, into which the `lines` of the cell are aligned.
+/// Lines outside of the content have no index.
+pub(crate) fn aligned_index(
     v_align: VAlign,
-    col_width: usize,
-    col_lines: &mut [String],
+    lines: usize,
     max_lines: usize,
     line_index: usize,
-    blank_char: char,
-) -> Cow<str> {
-    let index = match v_align {
-        VAlign::Top => {
-            let start_line_index = 0;
-            let end_line_index = col_lines.len() - 1;
-            if line_index >= start_line_index && line_index <= end_line_index {
-                Some(line_index)
-            } else {
-                None
-            }
-        }
-        VAlign::Bottom => {
-            let start_line_index = max_lines - col_lines.len();
-            let end_line_index = max_lines - 1;
-            if line_index >= start_line_index && line_index <= end_line_index {
-                Some(line_index - start_line_index)
-            } else {
-                None
-            }
-        }
-        VAlign::Middle => {
-            let start_line_index = (max_lines - col_lines.len()) / 2;
-            let end_line_index = start_line_index + col_lines.len() - 1;
-            if line_index >= start_line_index && line_index <= end_line_index {
-                Some(line_index - start_line_index)
-            } else {
-                None
-            }
-        }
+) -> Option<usize> {
+    let start_line_index = match v_align {
+        VAlign::Top => 0,
+        VAlign::Bottom => max_lines - lines,
+        VAlign::Middle => (max_lines - lines) / 2,
     };
-    if let Some(i) = index {
-        return pad(h_align, &mut col_lines[i], col_width, blank_char);
-    }
-    Cow::Owned(blank_char.to_string().repeat(col_width))
+    line_index
+        .checked_sub(start_line_index)
+        .filter(|&i| i < lines)
 }
 
-fn pad(h_align: HAlign, s: &mut String, width: usize, blank_char: char) -> Cow<str> {
-    let s_chars_len = s.chars().count();
-    if s_chars_len >= width {
-        let bytes_index = byte_index(s, width);
-        return s[..bytes_index].into();
-    }
-    let blanks = width - s_chars_len;
-    match h_align {
-        HAlign::Left => {
-            s.extend(std::iter::repeat(blank_char).take(blanks));
-            s.as_str().into()
-        }
-        HAlign::Right => {
-            let mut new_str = std::iter::repeat(blank_char)
-                .take(blanks)
-                .collect::<String>();
-            new_str.push_str(s);
-            new_str.into()
-        }
-        HAlign::Center => {
-            let left_blanks = blanks / 2;
-            let right_blanks = blanks - left_blanks;
-            let mut new_str = std::iter::repeat(blank_char)
-                .take(left_blanks)
-                .collect::<String>();
-            new_str.push_str(s);
-            new_str.extend(std::iter::repeat(blank_char).take(right_blanks));
-            new_str.into()
-        }
+/// Writes `s` aligned into `width` columns, truncated if it is wider.
+pub(crate) fn write_padded(
+    p: &mut Painter<'_, impl fmt::Write>,
+    h_align: HAlign,
+    s: &str,
+    width: usize,
+) -> fmt::Result {
+    let (s, closing) = text::truncated(s, width);
+    let s_width = text::width(s);
+    let blanks = width - s_width;
+    let (left_blanks, right_blanks) = match h_align {
+        HAlign::Left | HAlign::Justify => (0, blanks),
+        HAlign::Right | HAlign::Decimal(_) => (blanks, 0),
+        HAlign::Center => (blanks / 2, blanks - blanks / 2),
+        HAlign::Fill if s_width == 0 => (0, width),
         HAlign::Fill => {
-            let repeats = width / s_chars_len + 1;
-            let s = s.repeat(repeats);
-            let bytes_index = byte_index(&s, width);
-            s[..bytes_index].to_owned().into()
+            for _ in 0..width / s_width {
+                p.text(s, closing)?;
+            }
+            let (rest, closing) = text::truncated(s, width % s_width);
+            p.text(rest, closing)?;
+            return p.blanks(width % s_width - text::width(rest));
         }
-    }
+    };
+    p.blanks(left_blanks)?;
+    p.text(s, closing)?;
+    p.blanks(right_blanks)
+}
+
+/// Writes `s` into `width` columns, justified by adding blanks after each run
+/// of white spaces between its words. Lines that are too wide, or that have a
+/// single word, are left aligned.
+pub(crate) fn write_justified(
+    p: &mut Painter<'_, impl fmt::Write>,
+    s: &str,
+    width: usize,
+) -> fmt::Result {
+    let s = s.trim_end();
+    let s_width = text::width(s);
+    let gaps = word_starts(s).count();
+    if gaps == 0 || s_width >= width {
+        return write_padded(p, HAlign::Left, s, width);
+    }
+    let blanks = width - s_width;
+    let mut start = 0;
+    for (i, end) in word_starts(s).enumerate() {
+        p.text(&s[start..end], "")?;
+        p.blanks(blanks / gaps + usize::from(i < blanks % gaps))?;
+        start = end;
+    }
+    p.text(&s[start..], "")
 }
 
-fn byte_index(s: &str, char_index: usize) -> usize {
+/// Returns the byte indexes of the words of `s` preceded by white spaces,
+/// leading ones excluded.
+fn word_starts(s: &str) -> impl Iterator<Item = usize> + '_ {
     s.char_indices()
-        .take(char_index)
-        .last()
-        .map_or(0, |(i, ch)| i + ch.len_utf8())
+        .filter(move |&(i, c)| {
+            let before = &s[..i];
+            !c.is_whitespace()
+                && before.ends_with(char::is_whitespace)
+                && !before.trim_start().is_empty()
+        })
+        .map(|(i, _)| i)
+}
+
+/// Returns `true` if `s` has a digit, i.e. it is aligned by the
+/// [`HAlign::Decimal`] alignment.
+///
+/// [`HAlign::Decimal`]: enum.HAlign.html#variant.Decimal
+pub(crate) fn is_decimal(s: &str) -> bool {
+    s.bytes().any(|b| b.is_ascii_digit())
+}
+
+/// Returns the widths of the parts of `s` before and from the first occurrence
+/// of `separator`.
+pub(crate) fn decimal_widths(s: &str, separator: char) -> (usize, usize) {
+    match text::find(s, separator) {
+        Some(i) => (text::width(&s[..i]), text::width(&s[i..])),
+        None => (text::width(s), 0),
+    }
+}
+
+/// Writes `s` into `width` columns, aligned on the first occurrence of
+/// `separator`. `parts` are the widths of the parts before and from the
+/// separator shared by the aligned lines, which are right aligned as a whole.
+pub(crate) fn write_decimal(
+    p: &mut Painter<'_, impl fmt::Write>,
+    s: &str,
+    separator: char,
+    parts: (usize, usize),
+    width: usize,
+) -> fmt::Result {
+    let (int_width, frac_width) = parts;
+    let lead = width.saturating_sub(int_width + frac_width)
+        + int_width.saturating_sub(decimal_widths(s, separator).0);
+    let lead = lead.min(width);
+    p.blanks(lead)?;
+    write_padded(p, HAlign::Left, s, width - lead)
 }
 
 /// Builder for the [`Row`] type.
 ///
 /// [`Row`]: struct.Row.html
+#[derive(Debug)]
 pub struct RowBuilder {
     inner: Row,
 }
@@ -253,6 +309,17 @@ impl RowBuilder {
         self.inner
     }
 
+    /// Builds a [`Row`] from a [`RowBuilder`], or returns an error if its
+    /// default column span or the spans of its cells are `0`. The error is
+    /// located as if the row was the first row of the body of a grid.
+    ///
+    /// [`Row`]: struct.Row.html
+    /// [`RowBuilder`]: struct.RowBuilder.html
+    pub fn try_build(self) -> Result<Row, GridError> {
+        self.inner.validate(Section::Body, 0)?;
+        Ok(self.inner)
+    }
+
     /// Sets the default column span for all the cells of the grid. If a cell specifies
     /// a column span it will be used instead of the grids default value.
     pub fn default_colspan(mut self, default_col_span: usize) -> Self {
@@ -281,6 +348,27 @@ impl RowBuilder {
         self
     }
 
+    /// Sets the default overflow behaviour for all the cells of the row. If a cell specifies
+    /// an overflow behaviour it will be used instead of the rows default value.
+    pub fn default_overflow(mut self, default_overflow: Overflow) -> Self {
+        self.inner.default_options.overflow = Some(default_overflow);
+        self
+    }
+
+    /// Sets the default padding for all the cells of the row. If a cell specifies
+    /// a padding it will be used instead of the rows default value.
+    pub fn default_padding(mut self, default_padding: Padding) -> Self {
+        self.inner.default_options.padding = Some(default_padding);
+        self
+    }
+
+    /// Sets the default style for all the cells of the row. If a cell specifies
+    /// a style it will be used instead of the rows default value.
+    pub fn default_style(mut self, default_style: Style) -> Self {
+        self.inner.default_options.style = Some(default_style);
+        self
+    }
+
     /// Sets the width of each column in the [`Row`].
     ///
     /// [`Row`]: struct.Row.html
@@ -289,6 +377,16 @@ impl RowBuilder {
         self
     }
 
+    /// Sets the width of each individual column in the [`Row`]. Columns that
+    /// are not listed use the value of [`column_width`].
+    ///
+    /// [`Row`]: struct.Row.html
+    /// [`column_width`]: struct.RowBuilder.html#method.column_width
+    pub fn column_widths(mut self, column_widths: Vec<usize>) -> Self {
+        self.inner.column_widths = Some(column_widths);
+        self
+    }
+
     /// Sets the padding size between each column in the [`Row`].
     ///
     /// [`Row`]: struct.Row.html
@@ -297,6 +395,12 @@ impl RowBuilder {
         self
     }
 
+    /// Char filling the padding spaces between row columns.
+    pub fn gutter_char(mut self, gutter_char: char) -> Self {
+        self.inner.gutter_char = Some(gutter_char);
+        self
+    }
+
     /// Sets the cells collection of the [`Row`].
     ///
     /// [`Row`]: struct.Row.html
@@ -310,33 +414,110 @@ impl RowBuilder {
 mod tests {
     use super::*;
 
+    fn pad(h_align: HAlign, s: &str, width: usize, blank_char: char) -> String {
+        let mut result = String::new();
+        let mut painter = Painter::new(&mut result, Style::PLAIN, blank_char);
+        write_padded(&mut painter, h_align, s, width).unwrap();
+        result
+    }
+
+    fn justified(s: &str, width: usize) -> String {
+        let mut result = String::new();
+        write_justified(&mut Painter::new(&mut result, Style::PLAIN, '.'), s, width).unwrap();
+        result
+    }
+
+    #[test]
+    fn test_justify_distributes_blanks() {
+        let result = justified("a b c", 8);
+        let expected = "a ..b .c";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_justify_keeps_indentation() {
+        let result = justified("  a  b ", 8);
+        let expected = "  a  ..b";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_justify_single_word() {
+        let result = justified("abc", 5);
+        let expected = "abc..";
+        assert_eq!(result, expected);
+    }
+
+    fn decimal(s: &str, parts: (usize, usize), width: usize) -> String {
+        let mut result = String::new();
+        let mut painter = Painter::new(&mut result, Style::PLAIN, '_');
+        write_decimal(&mut painter, s, '.', parts, width).unwrap();
+        result
+    }
+
+    #[test]
+    fn test_decimal_aligned() {
+        let result = [decimal("1.5", (3, 3), 7), decimal("100.25", (3, 3), 7)];
+        let expected = ["___1.5_", "_100.25"];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_decimal_without_separator() {
+        let result = decimal("12", (3, 3), 7);
+        let expected = "__12___";
+        assert_eq!(result, expected);
+    }
+
     #[test]
-    fn test_byte_index_ascii() {
-        let s = "abc";
-        let result = byte_index(s, 2);
-        let expected = 2;
+    fn test_decimal_wider_than_width() {
+        let result = decimal("1.5", (4, 3), 5);
+        let expected = "___1.";
+        assert_eq!(result, expected);
+    }
+
+    fn col_line(
+        h_align: HAlign,
+        v_align: VAlign,
+        col_width: usize,
+        col_lines: &mut [String],
+        max_lines: usize,
+        line_index: usize,
+        blank_char: char,
+    ) -> String {
+        let line = aligned_index(v_align, col_lines.len(), max_lines, line_index);
+        pad(
+            h_align,
+            line.map_or("", |i| &col_lines[i]),
+            col_width,
+            blank_char,
+        )
+    }
+
+    #[test]
+    fn test_span_width_uniform() {
+        let result = span_width(&[], 3, 1, 0, 2);
+        let expected = 7;
         assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_byte_index_unicode1() {
-        let s = "aµc";
-        let result = byte_index(s, 2);
-        let expected = 3;
+    fn test_span_width_per_column() {
+        let result = span_width(&[2, 5, 8], 3, 1, 1, 2);
+        let expected = 14;
         assert_eq!(result, expected);
     }
 
     #[test]
-    fn test_byte_index_unicode2() {
-        let s = "µ∆c";
-        let result = byte_index(s, 2);
-        let expected = 5;
+    fn test_span_width_per_column_fallback() {
+        let result = span_width(&[2, 5], 3, 2, 1, 2);
+        let expected = 10;
         assert_eq!(result, expected);
     }
 
     #[test]
     fn test_pad_left_empty() {
-        let s = &mut "".into();
+        let s = "";
         let result = pad(HAlign::Right, s, 3, '.');
         let expected = String::from("...");
         assert_eq!(result, expected);
@@ -344,7 +525,7 @@ mod tests {
 
     #[test]
     fn test_pad_right_empty() {
-        let s = &mut "".into();
+        let s = "";
         let result = pad(HAlign::Left, s, 3, '.');
         let expected = String::from("...");
         assert_eq!(result, expected);
@@ -352,7 +533,7 @@ mod tests {
 
     #[test]
     fn test_pad_left() {
-        let s = &mut "a".into();
+        let s = "a";
         let result = pad(HAlign::Right, s, 3, '.');
         let expected = String::from("..a");
         assert_eq!(result, expected);
@@ -360,7 +541,7 @@ mod tests {
 
     #[test]
     fn test_pad_right() {
-        let s = &mut "a".into();
+        let s = "a";
         let result = pad(HAlign::Left, s, 3, '.');
         let expected = String::from("a..");
         assert_eq!(result, expected);
@@ -368,7 +549,7 @@ mod tests {
 
     #[test]
     fn test_pad_center() {
-        let s = &mut "a".into();
+        let s = "a";
         let result = pad(HAlign::Center, s, 3, '.');
         let expected = String::from(".a.");
         assert_eq!(result, expected);
@@ -376,7 +557,7 @@ mod tests {
 
     #[test]
     fn test_pad_fill1() {
-        let s = &mut "a".into();
+        let s = "a";
         let result = pad(HAlign::Fill, s, 3, '.');
         let expected = String::from("aaa");
         assert_eq!(result, expected);
@@ -384,7 +565,7 @@ mod tests {
 
     #[test]
     fn test_pad_fill2() {
-        let s = &mut "ab".into();
+        let s = "ab";
         let result = pad(HAlign::Fill, s, 3, '.');
         let expected = String::from("aba");
         assert_eq!(result, expected);
@@ -392,7 +573,7 @@ mod tests {
 
     #[test]
     fn test_pad_left_unicode() {
-        let s = &mut "∆".into();
+        let s = "∆";
         let result = pad(HAlign::Right, s, 3, '.');
         let expected = String::from("..∆");
         assert_eq!(result, expected);
@@ -400,7 +581,7 @@ mod tests {
 
     #[test]
     fn test_pad_right_unicode() {
-        let s = &mut "∆".into();
+        let s = "∆";
         let result = pad(HAlign::Left, s, 3, '.');
         let expected = String::from("∆..");
         assert_eq!(result, expected);
@@ -408,15 +589,50 @@ mod tests {
 
     #[test]
     fn test_pad_center_unicode() {
-        let s = &mut "∆".into();
+        let s = "∆";
         let result = pad(HAlign::Center, s, 3, '.');
         let expected = String::from(".∆.");
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_pad_fill_empty() {
+        let s = "";
+        let result = pad(HAlign::Fill, s, 3, '.');
+        let expected = String::from("...");
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_pad_left_wide() {
+        let s = "日";
+        let result = pad(HAlign::Left, s, 3, '.');
+        let expected = String::from("日.");
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_pad_truncate_wide() {
+        let s = "日本";
+        let result = pad(HAlign::Left, s, 3, '.');
+        let expected = String::from("日.");
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_pad_fill_wide() {
+        let s = "日";
+        let result = pad(HAlign::Fill, s, 3, '.');
+        let expected = String::from("日.");
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_pad_fill_unicode() {
-        let s = &mut "∆".into();
+        let s = "∆";
         let result = pad(HAlign::Fill, s, 3, '.');
         let expected = String::from("∆∆∆");
         assert_eq!(result, expected);
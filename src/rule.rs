@@ -0,0 +1,66 @@
+use crate::{
+    cell::{Cell, CellContent},
+    error::Section,
+    options::Options,
+};
+
+/// Rule of a grid, returning the options overriding the ones of a cell. See
+/// [`GridBuilder::rule`].
+///
+/// [`GridBuilder::rule`]: struct.GridBuilder.html#method.rule
+pub type CellRule = Box<dyn Fn(&CellContext<'_>) -> Options + Send + Sync>;
+
+/// A cell of a grid, given to its rules when the grid is rendered.
+#[derive(Debug)]
+pub struct CellContext<'a> {
+    /// Section of the row of the cell.
+    pub section: Section,
+
+    /// Index of the row of the cell in its section.
+    pub row: usize,
+
+    /// Index of the cell in its row.
+    pub index: usize,
+
+    /// First column covered by the cell.
+    pub column: usize,
+
+    /// Cells of the row of the cell, the cell included.
+    pub cells: &'a [Cell],
+
+    /// Index in [`cells`] of the cell covering each column of the row. Columns
+    /// covered by a cell spanning from a row above and the uncovered ones at
+    /// the end of the row are `None`.
+    ///
+    /// [`cells`]: struct.CellContext.html#structfield.cells
+    pub columns: &'a [Option<usize>],
+}
+
+impl<'a> CellContext<'a> {
+    /// Returns the text content of the cell, empty for a nested grid.
+    pub fn content(&self) -> &'a str {
+        self.text(self.index).unwrap_or("")
+    }
+
+    /// Returns the text content of the cell of the given index in [`cells`],
+    /// if any. The index counts the cells of the row, not the columns they
+    /// cover, see [`text_at`] for the latter. Nested grids have no text
+    /// content.
+    ///
+    /// [`cells`]: struct.CellContext.html#structfield.cells
+    /// [`text_at`]: struct.CellContext.html#method.text_at
+    pub fn text(&self, index: usize) -> Option<&'a str> {
+        match &self.cells.get(index)?.content {
+            CellContent::Text(text) => Some(text),
+            CellContent::Grid(_) => None,
+        }
+    }
+
+    /// Returns the text content of the cell of the same row covering the
+    /// given column, if any. See [`columns`] for the columns without a cell.
+    ///
+    /// [`columns`]: struct.CellContext.html#structfield.columns
+    pub fn text_at(&self, column: usize) -> Option<&'a str> {
+        self.text((*self.columns.get(column)?)?)
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{
+    error::Section,
+    grid::Grid,
+    layout::{Entry, Layout},
+    options::Options,
+    row::Row,
+};
+
+use std::{fmt, io};
+
+/// Adapts an [`io::Write`] into a [`fmt::Write`], keeping the io error that
+/// made the formatting fail.
+pub(crate) struct IoWriter<W> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> IoWriter<W> {
+    pub(crate) fn new(inner: W) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Converts the result of a formatting into the result of the writes.
+    pub(crate) fn result(&mut self, result: fmt::Result) -> io::Result<()> {
+        result.map_err(|_| {
+            self.error
+                .take()
+                .unwrap_or_else(|| io::Error::new(io::ErrorKind::Other, "formatter error"))
+        })
+    }
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+/// A row waiting to be laid out, either from the grid itself or given to
+/// [`GridWriter::write_row`].
+enum Pending<'a> {
+    Borrowed(&'a Row),
+    Owned(Row),
+}
+
+impl Pending<'_> {
+    fn row(&self) -> &Row {
+        match self {
+            Pending::Borrowed(row) => row,
+            Pending::Owned(row) => row,
+        }
+    }
+}
+
+/// Writes a [`Grid`] into an [`io::Write`] part by part, so that the rows do
+/// not have to be known, nor kept in memory, all at once.
+///
+/// The rows are laid out in blocks, each written and flushed as soon as the
+/// cells spanning its rows are complete. As the column widths can not depend
+/// on the rows that are not given yet, [`auto_size`] and [`total_width`] only
+/// take the header, the rows of the grid and the footer into account. Columns that turn out to be
+/// missing fall back to [`column_widths`] and [`column_width`]. Likewise,
+/// [`HAlign::Decimal`] cells are only aligned with the cells of their block.
+///
+/// Created by [`Grid::writer`]. The rows of the grid are written first,
+/// followed by the rows given to [`write_row`]. [`finish`] writes the footer.
+///
+/// [`Grid`]: struct.Grid.html
+/// [`auto_size`]: struct.Grid.html#structfield.auto_size
+/// [`total_width`]: struct.Grid.html#structfield.total_width
+/// [`column_widths`]: struct.Grid.html#structfield.column_widths
+/// [`column_width`]: struct.Grid.html#structfield.column_width
+/// [`HAlign::Decimal`]: enum.HAlign.html#variant.Decimal
+/// [`Grid::writer`]: struct.Grid.html#method.writer
+/// [`write_row`]: struct.GridWriter.html#method.write_row
+/// [`finish`]: struct.GridWriter.html#method.finish
+pub struct GridWriter<'a, W: io::Write> {
+    grid: &'a Grid,
+    writer: IoWriter<W>,
+    header_options: Options,
+    footer_options: Options,
+    widths: Vec<usize>,
+    pending: Vec<Pending<'a>>,
+
+    /// Whether the styles of the cells are written.
+    color: bool,
+
+    /// Number of rows written so far, the header excluded.
+    written: usize,
+
+    /// Whether anything was written so far.
+    started: bool,
+
+    /// Slots of the last written row.
+    previous: Option<Vec<Option<usize>>>,
+}
+
+impl<'a, W: io::Write> GridWriter<'a, W> {
+    pub(crate) fn new(grid: &'a Grid, writer: W, color: bool) -> io::Result<Self> {
+        let header_options = grid.header_options.or(&grid.default_options);
+        let footer_options = grid.footer_options.or(&grid.default_options);
+        let sections = [
+            (Section::Header, &grid.header, &header_options),
+            (Section::Body, &grid.rows, &grid.default_options),
+            (Section::Footer, &grid.footer, &footer_options),
+        ];
+        let mut entries = Vec::new();
+        let mut locations = Vec::new();
+        for (i, &(section, rows, default_options)) in sections.iter().enumerate() {
+            for (row_index, row) in rows.iter().enumerate() {
+                entries.push(Entry {
+                    row,
+                    default_options,
+                    separator: None,
+                    separator_below: None,
+                    section: i,
+                    stripe: grid.stripe(row_index).filter(|_| section == Section::Body),
+                });
+                locations.push((section, row_index));
+            }
+        }
+        let mut layout = Layout::new(entries, grid.column_options());
+        layout.apply_rules(&grid.rules, &locations);
+        let columns = layout.columns();
+        grid.apply_ragged_rows(&mut layout, columns);
+        let widths = grid.resolve_column_widths(&layout, grid.total_width);
+        let mut grid_writer = Self {
+            grid,
+            writer: IoWriter::new(writer),
+            header_options,
+            footer_options,
+            widths,
+            pending: Vec::new(),
+            color,
+            written: 0,
+            started: false,
+            previous: None,
+        };
+        for row in &grid.rows {
+            grid_writer.push(Pending::Borrowed(row))?;
+        }
+        Ok(grid_writer)
+    }
+
+    /// Adds a row to the grid. It is written as soon as the cells spanning
+    /// into it from the rows above, and its own cells, are complete.
+    pub fn write_row(&mut self, row: Row) -> io::Result<()> {
+        self.push(Pending::Owned(row))
+    }
+
+    /// Writes the rows left, the footer and the bottom border, then returns
+    /// the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.write_block(true)?;
+        Ok(self.writer.inner)
+    }
+
+    fn push(&mut self, row: Pending<'a>) -> io::Result<()> {
+        self.pending.push(row);
+        let page_end = self
+            .grid
+            .page_size
+            .filter(|&size| size > 0)
+            .is_some_and(|size| (self.written + self.pending.len()) % size == 0);
+        let spanned = self
+            .pending
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.row().cells.iter().map(move |c| (i, c)))
+            .map(|(i, cell)| i + cell.row_span.unwrap_or(1))
+            .max()
+            .unwrap_or(0);
+        if page_end || spanned <= self.pending.len() {
+            self.write_block(false)?;
+        }
+        Ok(())
+    }
+
+    /// Lays out and writes the pending rows, preceded by the header if they
+    /// start a page and followed by the footer if `last` is `true`.
+    fn write_block(&mut self, last: bool) -> io::Result<()> {
+        let grid = self.grid;
+        let header = match grid.page_size.filter(|&size| size > 0) {
+            _ if self.pending.is_empty() => !self.started,
+            Some(size) => self.written % size == 0,
+            None => self.written == 0,
+        };
+        let mut entries = Vec::new();
+        let mut locations = Vec::new();
+        if header {
+            for (i, row) in grid.header.iter().enumerate() {
+                entries.push(Entry {
+                    row,
+                    default_options: &self.header_options,
+                    separator: None,
+                    separator_below: grid.header_separator.filter(|_| i + 1 == grid.header.len()),
+                    section: 0,
+                    stripe: None,
+                });
+                locations.push((Section::Header, i));
+            }
+        }
+        for (i, row) in self.pending.iter().enumerate() {
+            entries.push(Entry {
+                row: row.row(),
+                default_options: &grid.default_options,
+                separator: None,
+                separator_below: None,
+                section: 1,
+                stripe: grid.stripe(self.written + i),
+            });
+            locations.push((Section::Body, self.written + i));
+        }
+        if last {
+            for (i, row) in grid.footer.iter().enumerate() {
+                let separator = grid
+                    .footer_separator
+                    .filter(|_| i == 0 && (self.started || !entries.is_empty()));
+                entries.push(Entry {
+                    row,
+                    default_options: &self.footer_options,
+                    separator,
+                    separator_below: None,
+                    section: 2,
+                    stripe: None,
+                });
+                locations.push((Section::Footer, i));
+            }
+        }
+        let mut layout = Layout::new(entries, grid.column_options());
+        layout.apply_rules(&grid.rules, &locations);
+        layout.previous = self.previous.take();
+        layout.gutter_char = grid.gutter_char();
+        layout.color = self.color;
+        let columns = layout.columns().max(self.widths.len());
+        grid.apply_ragged_rows(&mut layout, columns);
+        if self.widths.len() < columns {
+            let widths = grid.fixed_column_widths(columns);
+            self.widths.extend_from_slice(&widths[self.widths.len()..]);
+        }
+        let (chars, mut borders) = grid.borders_or_none();
+        borders.top &= !self.started;
+        borders.bottom &= last;
+        let started = self.started || borders.top || !layout.entries.is_empty();
+        layout.arrange(self.widths.clone(), grid.gutter_size(), chars, borders);
+        let result = layout.render(&mut self.writer);
+        self.writer.result(result)?;
+        self.previous = layout.last_slots().or(layout.previous.take());
+        drop(layout);
+        self.started = started;
+        self.written += self.pending.len();
+        self.pending.clear();
+        self.writer.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write;
+
+    struct Failing;
+
+    impl io::Write for Failing {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_io_writer_keeps_error() {
+        let mut writer = IoWriter::new(Failing);
+        let result = write!(writer, "a");
+        let result = writer.result(result).map_err(|e| e.kind());
+        let expected = Err(io::ErrorKind::BrokenPipe);
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,353 @@
+use crate::text;
+
+use std::fmt::{self, Write};
+
+/// Colour of the text or of the background of a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+
+    /// Colour of the given index in the 256 colours palette of the terminal.
+    Ansi256(u8),
+
+    /// Colour of the given red, green and blue components, for terminals
+    /// supporting true colours.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Writes the SGR parameters setting the colour, as the background colour
+    /// if `background` is `true`.
+    fn write_params(self, f: &mut impl Write, background: bool) -> fmt::Result {
+        let base = if background { 40 } else { 30 };
+        let index = match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::BrightBlack => 60,
+            Color::BrightRed => 61,
+            Color::BrightGreen => 62,
+            Color::BrightYellow => 63,
+            Color::BrightBlue => 64,
+            Color::BrightMagenta => 65,
+            Color::BrightCyan => 66,
+            Color::BrightWhite => 67,
+            Color::Ansi256(n) => return write!(f, "{};5;{}", base + 8, n),
+            Color::Rgb(r, g, b) => return write!(f, "{};2;{};{};{}", base + 8, r, g, b),
+        };
+        write!(f, "{}", base + index)
+    }
+}
+
+/// Colours and text attributes of a cell, written as SGR escape sequences
+/// around its content.
+///
+/// The text attributes only apply to the content. The blanks padding it only
+/// take the colours and [`reverse`], so that underlined text is not extended
+/// by underlined blanks.
+///
+/// [`reverse`]: struct.Style.html#structfield.reverse
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Style {
+    /// Colour of the text. If `None` specified, the colour of the terminal is used.
+    pub fg: Option<Color>,
+
+    /// Colour of the background. If `None` specified, the background of the
+    /// terminal is used.
+    pub bg: Option<Color>,
+
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+
+    /// Swaps the colours of the text and of the background.
+    pub reverse: bool,
+}
+
+impl Style {
+    /// No colours nor text attributes. (default)
+    pub const PLAIN: Style = Style {
+        fg: None,
+        bg: None,
+        bold: false,
+        dim: false,
+        italic: false,
+        underline: false,
+        reverse: false,
+    };
+
+    /// Returns the style with the given text colour.
+    pub const fn fg(self, fg: Color) -> Self {
+        Style {
+            fg: Some(fg),
+            ..self
+        }
+    }
+
+    /// Returns the style with the given background colour.
+    pub const fn bg(self, bg: Color) -> Self {
+        Style {
+            bg: Some(bg),
+            ..self
+        }
+    }
+
+    /// Returns the style with bold text.
+    pub const fn bold(self) -> Self {
+        Style { bold: true, ..self }
+    }
+
+    /// Returns the style with dim text.
+    pub const fn dim(self) -> Self {
+        Style { dim: true, ..self }
+    }
+
+    /// Returns the style with italic text.
+    pub const fn italic(self) -> Self {
+        Style {
+            italic: true,
+            ..self
+        }
+    }
+
+    /// Returns the style with underlined text.
+    pub const fn underline(self) -> Self {
+        Style {
+            underline: true,
+            ..self
+        }
+    }
+
+    /// Returns the style with the colours of the text and of the background
+    /// swapped.
+    pub const fn reverse(self) -> Self {
+        Style {
+            reverse: true,
+            ..self
+        }
+    }
+
+    /// Returns the style of the blanks padding the text of the style.
+    fn blanks(self) -> Self {
+        Style {
+            fg: self.fg,
+            bg: self.bg,
+            reverse: self.reverse,
+            ..Style::PLAIN
+        }
+    }
+
+    /// Writes the SGR escape sequence setting the style.
+    fn write_sgr(self, f: &mut impl Write) -> fmt::Result {
+        f.write_str("\x1b[")?;
+        let attributes = [
+            (self.bold, "1"),
+            (self.dim, "2"),
+            (self.italic, "3"),
+            (self.underline, "4"),
+            (self.reverse, "7"),
+        ];
+        let mut separator = "";
+        for (_, code) in attributes.iter().filter(|(set, _)| *set) {
+            f.write_str(separator)?;
+            f.write_str(code)?;
+            separator = ";";
+        }
+        if let Some(fg) = self.fg {
+            f.write_str(separator)?;
+            fg.write_params(f, false)?;
+            separator = ";";
+        }
+        if let Some(bg) = self.bg {
+            f.write_str(separator)?;
+            bg.write_params(f, true)?;
+        }
+        f.write_char('m')
+    }
+}
+
+/// Writes the lines of a cell, styling its text and its blanks separately.
+/// Consecutive parts of the same style share a single escape sequence.
+pub(crate) struct Painter<'f, W> {
+    f: &'f mut W,
+    text: Style,
+    blanks: Style,
+    blank_char: char,
+
+    /// Style set by the last escape sequence written and not reset yet.
+    open: Option<Style>,
+}
+
+impl<'f, W: Write> Painter<'f, W> {
+    pub(crate) fn new(f: &'f mut W, style: Style, blank_char: char) -> Self {
+        Self {
+            f,
+            text: style,
+            blanks: style.blanks(),
+            blank_char,
+            open: None,
+        }
+    }
+
+    /// Writes `s` in the style of the text, followed by the `closing` escape
+    /// sequences of its own styles. The style is set again after each reset
+    /// in the content.
+    pub(crate) fn text(&mut self, s: &str, closing: &str) -> fmt::Result {
+        if s.is_empty() {
+            return self.f.write_str(closing);
+        }
+        self.paint(self.text)?;
+        let open = match self.open {
+            Some(style) => style,
+            None => {
+                self.f.write_str(s)?;
+                return self.f.write_str(closing);
+            }
+        };
+        let mut start = 0;
+        for end in text::reset_ends(s) {
+            self.f.write_str(&s[start..end])?;
+            open.write_sgr(self.f)?;
+            start = end;
+        }
+        self.f.write_str(&s[start..])?;
+        if !closing.is_empty() {
+            self.f.write_str(closing)?;
+            open.write_sgr(self.f)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `count` blank chars in the style of the blanks.
+    pub(crate) fn blanks(&mut self, count: usize) -> fmt::Result {
+        if count == 0 {
+            return Ok(());
+        }
+        self.paint(self.blanks)?;
+        crate::layout::write_repeated(self.f, self.blank_char, count)
+    }
+
+    /// Resets the style left open, if any.
+    pub(crate) fn finish(&mut self) -> fmt::Result {
+        match self.open.take() {
+            Some(_) => self.f.write_str(text::RESET),
+            None => Ok(()),
+        }
+    }
+
+    /// Switches to `style`, unless it is already set.
+    fn paint(&mut self, style: Style) -> fmt::Result {
+        if self.open == Some(style) || (self.open.is_none() && style == Style::PLAIN) {
+            return Ok(());
+        }
+        self.finish()?;
+        if style != Style::PLAIN {
+            style.write_sgr(self.f)?;
+            self.open = Some(style);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sgr(style: Style) -> String {
+        let mut result = String::new();
+        style.write_sgr(&mut result).unwrap();
+        result
+    }
+
+    fn paint(style: Style, write: impl Fn(&mut Painter<'_, String>) -> fmt::Result) -> String {
+        let mut result = String::new();
+        let mut painter = Painter::new(&mut result, style, '.');
+        write(&mut painter).unwrap();
+        painter.finish().unwrap();
+        result
+    }
+
+    #[test]
+    fn test_sgr_attributes_and_16_colors() {
+        let style = Style::PLAIN
+            .bold()
+            .underline()
+            .fg(Color::Red)
+            .bg(Color::BrightBlue);
+        let result = sgr(style);
+        let expected = "\x1b[1;4;31;104m";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sgr_256_and_true_colors() {
+        let style = Style::PLAIN.fg(Color::Ansi256(208)).bg(Color::Rgb(1, 2, 3));
+        let result = sgr(style);
+        let expected = "\x1b[38;5;208;48;2;1;2;3m";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_painter_plain_writes_no_escapes() {
+        let result = paint(Style::PLAIN, |p| {
+            p.blanks(1)?;
+            p.text("a", "")?;
+            p.blanks(2)
+        });
+        let expected = ".a..";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_painter_styles_text_and_blanks_separately() {
+        let style = Style::PLAIN.underline().bg(Color::Blue);
+        let result = paint(style, |p| {
+            p.blanks(1)?;
+            p.text("a", "")?;
+            p.text("b", "")?;
+            p.blanks(2)
+        });
+        let expected = "\x1b[44m.\x1b[0m\x1b[4;44mab\x1b[0m\x1b[44m..\x1b[0m";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_painter_shares_escape_of_same_styles() {
+        let style = Style::PLAIN.bg(Color::Blue);
+        let result = paint(style, |p| {
+            p.text("a", "")?;
+            p.blanks(1)
+        });
+        let expected = "\x1b[44ma.\x1b[0m";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_painter_restores_style_after_content_reset() {
+        let style = Style::PLAIN.bold();
+        let result = paint(style, |p| p.text("\x1b[31ma\x1b[0mb", ""));
+        let expected = "\x1b[1m\x1b[31ma\x1b[0m\x1b[1mb\x1b[0m";
+        assert_eq!(result, expected);
+    }
+}
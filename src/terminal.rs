@@ -0,0 +1,58 @@
+use std::ffi::OsStr;
+
+/// Returns the width of the terminal in number of chars.
+///
+/// The `COLUMNS` environment variable is used if it is set to a positive number.
+/// Otherwise, with the `terminal-size` feature enabled on unix, the size of the
+/// terminal connected to the standard output, error or input is queried.
+/// Returns `None` if neither is available.
+pub fn terminal_width() -> Option<usize> {
+    columns_width(std::env::var_os("COLUMNS").as_deref()).or_else(tty_width)
+}
+
+/// Parses the value of the `COLUMNS` environment variable, if it is set to a
+/// positive number.
+fn columns_width(columns: Option<&OsStr>) -> Option<usize> {
+    columns?
+        .to_str()?
+        .trim()
+        .parse()
+        .ok()
+        .filter(|&columns| columns > 0)
+}
+
+#[cfg(all(unix, feature = "terminal-size"))]
+fn tty_width() -> Option<usize> {
+    [libc::STDOUT_FILENO, libc::STDERR_FILENO, libc::STDIN_FILENO]
+        .iter()
+        .find_map(|&fd| {
+            // SAFETY: `winsize` is plain data, and `TIOCGWINSZ` only writes
+            // into the one it is given.
+            let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+            let result = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) };
+            Some(usize::from(size.ws_col)).filter(|&columns| result == 0 && columns > 0)
+        })
+}
+
+#[cfg(not(all(unix, feature = "terminal-size")))]
+fn tty_width() -> Option<usize> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_columns_width() {
+        let result = [
+            columns_width(Some(OsStr::new("80"))),
+            columns_width(Some(OsStr::new(" 120\n"))),
+            columns_width(Some(OsStr::new("0"))),
+            columns_width(Some(OsStr::new("wide"))),
+            columns_width(None),
+        ];
+        let expected = [Some(80), Some(120), None, None, None];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,544 @@
+use crate::cell::EllipsisPosition;
+
+use std::borrow::Cow;
+
+const ESC: char = '\x1b';
+pub(crate) const RESET: &str = "\x1b[0m";
+const HYPERLINK_END: &str = "\x1b]8;;\x1b\\";
+
+/// Iterator over the grapheme clusters of a string along with their byte
+/// offsets and their display widths in the terminal.
+///
+/// Escape sequences (e.g. SGR colours or OSC 8 hyperlinks) are yielded as
+/// single zero width items, so they are never measured nor split.
+struct Graphemes<'a> {
+    s: &'a str,
+    index: usize,
+    // End of the run of plain text the index is in.
+    text_end: usize,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = (usize, &'a str, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.s[self.index..];
+        let start = self.index;
+        if rest.is_empty() {
+            return None;
+        }
+        if rest.starts_with(ESC) {
+            let len = escape_len(rest);
+            self.index += len;
+            return Some((start, &rest[..len], 0));
+        }
+        if self.text_end <= start {
+            self.text_end = rest.find(ESC).map_or(self.s.len(), |i| start + i);
+        }
+        let (g, w) = next_grapheme(&self.s[start..self.text_end]);
+        self.index += g.len();
+        Some((start, g, w))
+    }
+}
+
+fn graphemes(s: &str) -> Graphemes<'_> {
+    Graphemes {
+        s,
+        index: 0,
+        text_end: 0,
+    }
+}
+
+/// Returns the first grapheme cluster of a non empty `s` along with its
+/// display width in the terminal.
+#[cfg(feature = "unicode")]
+fn next_grapheme(s: &str) -> (&str, usize) {
+    use unicode_segmentation::UnicodeSegmentation;
+    use unicode_width::UnicodeWidthStr;
+
+    let g = s.graphemes(true).next().unwrap_or(s);
+    (g, g.width())
+}
+
+/// Returns the first char of a non empty `s`, taking it as 1 column wide.
+#[cfg(not(feature = "unicode"))]
+fn next_grapheme(s: &str) -> (&str, usize) {
+    let len = s.chars().next().map_or(0, char::len_utf8);
+    (&s[..len], 1)
+}
+
+/// Returns the length in bytes of the escape sequence `s` starts with.
+///
+/// CSI sequences (`ESC [ ... final`) and OSC sequences (`ESC ] ... BEL` or
+/// `ESC ] ... ESC \`) are recognized, any other escape is taken as `ESC` plus
+/// one char. An unterminated sequence takes the rest of the string.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    match bytes.get(1) {
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map_or(s.len(), |i| i + 3),
+        Some(b']') => {
+            let mut i = 2;
+            while i < bytes.len() {
+                match bytes[i] {
+                    0x07 => return i + 1,
+                    0x1b if bytes.get(i + 1) == Some(&b'\\') => return i + 2,
+                    _ => i += 1,
+                }
+            }
+            s.len()
+        }
+        Some(_) => 1 + s[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+/// Returns the escape sequences that close the styles and the hyperlink left
+/// open at the end of `s`.
+fn closing_escapes(s: &str) -> &'static str {
+    let mut styled = false;
+    let mut linked = false;
+    for (_, g, _) in graphemes(s).filter(|(_, g, _)| g.starts_with(ESC)) {
+        if let Some(params) = g.strip_prefix("\x1b[").and_then(|g| g.strip_suffix('m')) {
+            styled = !(params.is_empty() || params == "0");
+        } else if let Some(params) = g.strip_prefix("\x1b]8;") {
+            let uri = params.split(';').nth(1).unwrap_or("");
+            linked = !uri.trim_end_matches(['\x07', '\x1b', '\\']).is_empty();
+        }
+    }
+    match (styled, linked) {
+        (false, false) => "",
+        (true, false) => RESET,
+        (false, true) => HYPERLINK_END,
+        (true, true) => "\x1b[0m\x1b]8;;\x1b\\",
+    }
+}
+
+/// Returns all the escape sequences of `s`, in order.
+fn escapes(s: &str) -> String {
+    graphemes(s)
+        .map(|(_, g, _)| g)
+        .filter(|g| g.starts_with(ESC))
+        .collect()
+}
+
+/// Cuts `s` to its part between the byte indexes `start` and `end`, keeping
+/// the styles of the cut part intact. The escape sequences preceding `start`
+/// are replayed before it, and the ones left open at `end` are closed.
+fn slice(s: &str, start: usize, end: usize) -> Cow<'_, str> {
+    if !s.contains(ESC) {
+        return s[start..end].into();
+    }
+    let mut result = escapes(&s[..start]);
+    result.push_str(&s[start..end]);
+    result.push_str(closing_escapes(&s[..end]));
+    result.into()
+}
+
+/// Truncates `s` to at most `width` columns. Returns the kept part along with
+/// the escape sequences closing the styles and hyperlinks it leaves open, to
+/// be written right after it.
+pub(crate) fn truncated(s: &str, width: usize) -> (&str, &'static str) {
+    let index = prefix_index(s, width);
+    if index < s.len() {
+        (&s[..index], closing_escapes(&s[..index]))
+    } else {
+        (s, "")
+    }
+}
+
+/// Returns the byte index of the first occurrence of `c` in `s`, outside of
+/// the escape sequences.
+pub(crate) fn find(s: &str, c: char) -> Option<usize> {
+    graphemes(s)
+        .find(|(_, g, _)| !g.starts_with(ESC) && g.starts_with(c))
+        .map(|(i, _, _)| i)
+}
+
+/// Returns the byte indexes right after the escape sequences of `s` that
+/// reset all the styles.
+pub(crate) fn reset_ends(s: &str) -> impl Iterator<Item = usize> + '_ {
+    graphemes(s)
+        .filter(|(_, g, _)| *g == RESET || *g == "\x1b[m")
+        .map(|(i, g, _)| i + g.len())
+}
+
+/// Returns the display width of `s` in the terminal.
+pub(crate) fn width(s: &str) -> usize {
+    graphemes(s).map(|(_, _, w)| w).sum()
+}
+
+/// Returns the byte index where the longest prefix of `s` that is not wider
+/// than `width` ends. Grapheme clusters are never split, so the prefix can be
+/// narrower than `width` if a double width glyph does not fit.
+pub(crate) fn prefix_index(s: &str, width: usize) -> usize {
+    let mut prefix_width = 0;
+    let mut prefix_end = 0;
+    for (i, g, w) in graphemes(s) {
+        if prefix_width + w > width {
+            return prefix_end;
+        }
+        prefix_width += w;
+        // Escape sequences are only kept if some text follows them.
+        if !g.starts_with(ESC) {
+            prefix_end = i + g.len();
+        }
+    }
+    s.len()
+}
+
+/// Returns the byte index where the longest suffix of `s` that is not wider
+/// than `width` starts. Grapheme clusters are never split.
+pub(crate) fn suffix_index(s: &str, width: usize) -> usize {
+    let graphemes = graphemes(s).collect::<Vec<_>>();
+    let mut suffix_width = 0;
+    let mut index = s.len();
+    for &(i, _, w) in graphemes.iter().rev() {
+        if suffix_width + w > width {
+            break;
+        }
+        suffix_width += w;
+        index = i;
+    }
+    index
+}
+
+/// Wraps a single line on word boundaries into lines of at most `width` columns.
+///
+/// The white spaces at the wrapping points are dropped, while the ones between
+/// the words of a line are kept as they are. Words wider than `width` are broken
+/// at grapheme boundaries.
+pub(crate) fn wrap(line: &str, width: usize) -> Vec<Cow<'_, str>> {
+    if width == 0 || self::width(line) <= width {
+        return vec![line.into()];
+    }
+    let mut lines = Vec::new();
+    // Byte range and display width of the line being built.
+    let mut current: Option<(usize, usize, usize)> = None;
+    for (word_start, word) in words(line) {
+        let word_width = self::width(word);
+        if let Some((start, end, current_width)) = current {
+            let gap = self::width(&line[end..word_start]);
+            if current_width + gap + word_width <= width {
+                current = Some((
+                    start,
+                    word_start + word.len(),
+                    current_width + gap + word_width,
+                ));
+                continue;
+            }
+            lines.push(slice(line, start, end));
+        }
+        let mut word_start = word_start;
+        let mut word = word;
+        let mut word_width = word_width;
+        while word_width > width {
+            let mut index = prefix_index(word, width);
+            if index == 0 {
+                // A single grapheme wider than the line, take it anyway.
+                index = graphemes(word).nth(1).map_or(word.len(), |(i, _, _)| i);
+            }
+            lines.push(slice(line, word_start, word_start + index));
+            word_start += index;
+            word = &word[index..];
+            word_width = self::width(word);
+        }
+        current = if word.is_empty() {
+            None
+        } else {
+            Some((word_start, word_start + word.len(), word_width))
+        };
+    }
+    if let Some((start, end, _)) = current {
+        lines.push(slice(line, start, end));
+    }
+    if lines.is_empty() {
+        lines.push("".into());
+    }
+    lines
+}
+
+/// Truncates a line to at most `width` columns, replacing the cut part by `marker`.
+/// Lines that already fit are returned as they are. The marker itself is never styled.
+pub(crate) fn ellipsize<'a>(
+    line: &'a str,
+    width: usize,
+    marker: &str,
+    position: EllipsisPosition,
+) -> Cow<'a, str> {
+    if self::width(line) <= width {
+        return line.into();
+    }
+    let marker_width = self::width(marker);
+    if marker_width >= width {
+        return marker[..prefix_index(marker, width)].to_owned().into();
+    }
+    let keep = width - marker_width;
+    let (head, tail) = match position {
+        EllipsisPosition::Start => (0, keep),
+        EllipsisPosition::Middle => (keep - keep / 2, keep / 2),
+        EllipsisPosition::End => (keep, 0),
+    };
+    let head_end = prefix_index(line, head);
+    let tail_start = suffix_index(line, tail);
+    let mut result = String::with_capacity(line.len() + marker.len());
+    result.push_str(&line[..head_end]);
+    result.push_str(closing_escapes(&line[..head_end]));
+    result.push_str(marker);
+    if tail_start < line.len() {
+        result.push_str(&slice(line, tail_start, line.len()));
+    }
+    result.into()
+}
+
+/// Splits a line into its words along with their byte offsets.
+fn words(line: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut word_start = None;
+    for (i, ch) in line.char_indices() {
+        match (ch.is_whitespace(), word_start) {
+            (false, None) => word_start = Some(i),
+            (true, Some(start)) => {
+                words.push((start, &line[start..i]));
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = word_start {
+        words.push((start, &line[start..]));
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_len_csi() {
+        let result = escape_len("\x1b[1;31mabc");
+        let expected = 7;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_escape_len_osc() {
+        let result = escape_len("\x1b]8;;http://a.b\x1b\\link");
+        let expected = 17;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_escape_len_unterminated() {
+        let result = escape_len("\x1b[31");
+        let expected = 4;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_width_skips_escapes() {
+        let result = width("\x1b[31mred\x1b[0m \x1b]8;;http://a.b\x07link\x1b]8;;\x07");
+        let expected = 8;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_truncate_styled() {
+        let (s, closing) = truncated("\x1b[31mred\x1b[0m", 2);
+        let result = format!("{}{}", s, closing);
+        let expected = "\x1b[31mre\x1b[0m";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_truncate_reset() {
+        let (s, closing) = truncated("\x1b[31mred\x1b[0m plain", 5);
+        let result = format!("{}{}", s, closing);
+        let expected = "\x1b[31mred\x1b[0m p";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_truncate_hyperlink() {
+        let (s, closing) = truncated("\x1b]8;;http://a.b\x1b\\link\x1b]8;;\x1b\\", 2);
+        let result = format!("{}{}", s, closing);
+        let expected = "\x1b]8;;http://a.b\x1b\\li\x1b]8;;\x1b\\";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_find_skips_escapes() {
+        let result = find("\x1b]8;;http://a.b\x1b\\12.5\x1b]8;;\x1b\\", '.');
+        let expected = Some(19);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_styled() {
+        let result = wrap("\x1b[1mab cd\x1b[0m", 2);
+        let expected = vec!["\x1b[1mab\x1b[0m", "\x1b[1mcd\x1b[0m"];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ellipsize_styled_start() {
+        let result = ellipsize("\x1b[31mabcdef\x1b[0m", 3, "…", EllipsisPosition::Start);
+        let expected = "…\x1b[31mef\x1b[0m";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_prefix_index_ascii() {
+        let result = prefix_index("abc", 2);
+        let expected = 2;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_prefix_index_unicode1() {
+        let result = prefix_index("aµc", 2);
+        let expected = 3;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_prefix_index_unicode2() {
+        let result = prefix_index("µ∆c", 2);
+        let expected = 5;
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_suffix_index_unicode() {
+        let result = suffix_index("µ∆c", 2);
+        let expected = 2;
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_width_wide_and_combining() {
+        let result = width("日本e\u{301}👍");
+        let expected = 7;
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_prefix_index_does_not_split_wide_glyph() {
+        let result = prefix_index("a日本", 2);
+        let expected = 1;
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_prefix_index_does_not_split_grapheme() {
+        let result = prefix_index("e\u{301}a", 1);
+        let expected = 3;
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_wrap_wide_glyphs() {
+        let result = wrap("日本語 abc", 3);
+        let expected = vec!["日", "本", "語", "abc"];
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn test_ellipsize_wide_glyphs() {
+        let result = ellipsize("日本語", 4, "…", EllipsisPosition::End);
+        let expected = "日…";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ellipsize_fits() {
+        let result = ellipsize("abc", 3, "…", EllipsisPosition::End);
+        let expected = "abc";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ellipsize_end() {
+        let result = ellipsize("abcdef", 4, "…", EllipsisPosition::End);
+        let expected = "abc…";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ellipsize_start() {
+        let result = ellipsize("abcdef", 4, "...", EllipsisPosition::Start);
+        let expected = "...f";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ellipsize_middle() {
+        let result = ellipsize("/usr/local/bin", 9, "…", EllipsisPosition::Middle);
+        let expected = "/usr…/bin";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ellipsize_marker_wider_than_width() {
+        let result = ellipsize("abcdef", 2, "...", EllipsisPosition::End);
+        let expected = "..";
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_fits() {
+        let result = wrap("ab cd", 5);
+        let expected = vec!["ab cd"];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_words() {
+        let result = wrap("ab cd ef", 5);
+        let expected = vec!["ab cd", "ef"];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_keeps_inner_spaces() {
+        let result = wrap("a  b   cdef", 5);
+        let expected = vec!["a  b", "cdef"];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_long_word() {
+        let result = wrap("a abcdefgh b", 3);
+        let expected = vec!["a", "abc", "def", "gh", "b"];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_long_word_exact() {
+        let result = wrap("abcdef gh", 3);
+        let expected = vec!["abc", "def", "gh"];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_unicode() {
+        let result = wrap("µ∆c ∆∆", 2);
+        let expected = vec!["µ∆", "c", "∆∆"];
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_wrap_blank() {
+        let result = wrap("     ", 2);
+        let expected = vec![""];
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    border::{BorderChars, BorderStyle, Borders},
+    cell::Padding,
+    options::Options,
+    style::Style,
+};
+
+/// Set of options of a grid shaping its look, applied at once by
+/// [`GridBuilder::theme`]. Each field replaces the equivalent field of the
+/// grid, `None` included.
+///
+/// [`GridBuilder::theme`]: struct.GridBuilder.html#method.theme
+#[derive(Clone, Debug, Default)]
+pub struct Theme {
+    /// Replaces the [`default_options`] of the grid.
+    ///
+    /// [`default_options`]: struct.Grid.html#structfield.default_options
+    pub default_options: Options,
+
+    /// Replaces the [`header_options`] of the grid.
+    ///
+    /// [`header_options`]: struct.Grid.html#structfield.header_options
+    pub header_options: Options,
+
+    /// Replaces the [`header_separator`] of the grid.
+    ///
+    /// [`header_separator`]: struct.Grid.html#structfield.header_separator
+    pub header_separator: Option<char>,
+
+    /// Replaces the [`padding_size`] of the grid.
+    ///
+    /// [`padding_size`]: struct.Grid.html#structfield.padding_size
+    pub padding_size: Option<usize>,
+
+    /// Replaces the [`gutter_char`] of the grid.
+    ///
+    /// [`gutter_char`]: struct.Grid.html#structfield.gutter_char
+    pub gutter_char: Option<char>,
+
+    /// Replaces the [`border_style`] of the grid.
+    ///
+    /// [`border_style`]: struct.Grid.html#structfield.border_style
+    pub border_style: Option<BorderStyle>,
+
+    /// Replaces the [`borders`] of the grid.
+    ///
+    /// [`borders`]: struct.Grid.html#structfield.borders
+    pub borders: Option<Borders>,
+}
+
+impl Theme {
+    /// No borders, columns separated by 2 spaces and the header underlined
+    /// by a line of `-`.
+    pub fn plain() -> Self {
+        Theme {
+            header_separator: Some('-'),
+            padding_size: Some(2),
+            ..Theme::default()
+        }
+    }
+
+    /// No borders and columns separated by a single space.
+    pub fn compact() -> Self {
+        Theme {
+            padding_size: Some(1),
+            ..Theme::default()
+        }
+    }
+
+    /// Columns separated by `|` and the header underlined by `|---|`, like
+    /// the tables of Markdown.
+    pub fn markdown() -> Self {
+        let chars = BorderChars {
+            horizontal: '-',
+            vertical: '|',
+            top_left: '|',
+            top: '|',
+            top_right: '|',
+            left: '|',
+            cross: '|',
+            right: '|',
+            bottom_left: '|',
+            bottom: '|',
+            bottom_right: '|',
+        };
+        Theme {
+            default_options: Options {
+                padding: Some(Padding::horizontal(1)),
+                ..Options::default()
+            },
+            header_separator: Some('-'),
+            border_style: Some(BorderStyle::Custom(chars)),
+            borders: Some(Borders {
+                top: false,
+                bottom: false,
+                ..Borders::COLUMNS
+            }),
+            ..Theme::default()
+        }
+    }
+
+    /// Frame and columns drawn by [`BorderStyle::Rounded`], and a bold header
+    /// separated by a border line.
+    ///
+    /// [`BorderStyle::Rounded`]: enum.BorderStyle.html#variant.Rounded
+    pub fn rounded() -> Self {
+        Theme {
+            border_style: Some(BorderStyle::Rounded),
+            borders: Some(Borders::COLUMNS),
+            ..Theme::boxed()
+        }
+    }
+
+    /// All the borders drawn by [`BorderStyle::Heavy`], and a bold header.
+    ///
+    /// [`BorderStyle::Heavy`]: enum.BorderStyle.html#variant.Heavy
+    pub fn heavy() -> Self {
+        Theme {
+            border_style: Some(BorderStyle::Heavy),
+            borders: Some(Borders::ALL),
+            ..Theme::boxed()
+        }
+    }
+
+    /// Cells padded by a space on each side and a bold header separated by a
+    /// border line, shared by the themes with borders.
+    fn boxed() -> Self {
+        Theme {
+            default_options: Options {
+                padding: Some(Padding::horizontal(1)),
+                ..Options::default()
+            },
+            header_options: Options {
+                style: Some(Style::PLAIN.bold()),
+                ..Options::default()
+            },
+            header_separator: Some('-'),
+            ..Theme::default()
+        }
+    }
+}
@@ -1,4 +1,10 @@
-use cli_grid::{Cell, Grid, HAlign, Row, VAlign};
+#![allow(clippy::useless_conversion)]
+
+use cli_grid::{
+    BorderStyle, Borders, Cell, CellContent, CellLayout, Color, ColorChoice, EllipsisPosition,
+    Grid, GridBuilder, GridError, HAlign, Location, Options, Overflow, Padding, RaggedRows,
+    RenderConfig, Row, Section, Style, Theme, VAlign,
+};
 
 #[test]
 fn test_grid_1x1() {
@@ -386,3 +392,1765 @@ fn test_nested_grids_3x3_multi_line_center_middle() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_grid_3x3_with_column_widths() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("1".into(), 1),
+            Cell::new("1".into(), 1),
+            Cell::new("1".into(), 1),
+        ]),
+        Row::new(vec![Cell::new("1".into(), 1), Cell::new("2".into(), 2)]),
+        Row::new(vec![Cell::new("2".into(), 2), Cell::new("1".into(), 1)]),
+    ])
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .column_widths(vec![2, 6])
+    .column_width(3)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n",
+        "1. 1..... 1..",
+        "1. 2.........",
+        "2........ 1..",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_2x1_with_row_column_width_and_padding_size() {
+    let grid = Grid::builder(vec![Row::builder(vec![
+        Cell::new("a".into(), 1),
+        Cell::new("b".into(), 1),
+    ])
+    .column_width(4)
+    .padding_size(2)
+    .build()])
+    .default_blank_char('.')
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n",
+        "a...  b...",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_2x1_with_row_column_widths_and_gutter_char() {
+    let grid = Grid::builder(vec![Row::builder(vec![
+        Cell::new("a".into(), 1),
+        Cell::new("b".into(), 1),
+    ])
+    .column_widths(vec![4])
+    .column_width(4)
+    .gutter_char('|')
+    .build()])
+    .default_blank_char('.')
+    .column_width(2)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n",
+        "a...|b.",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_try_build_conflicting_row_settings() {
+    let rows = || {
+        vec![
+            Row::builder(vec![Cell::new("a".into(), 1), Cell::new("b".into(), 1)])
+                .column_width(3)
+                .build(),
+            Row::builder(vec![Cell::new("c".into(), 1), Cell::new("d".into(), 1)])
+                .column_width(6)
+                .padding_size(3)
+                .build(),
+        ]
+    };
+
+    let result = [
+        Grid::builder(rows()).try_build().err(),
+        Grid::builder(rows()).column_width(4).try_build().err(),
+    ];
+    let expected = [
+        Some(GridError::ConflictingRowSettings(Location::Row(
+            Section::Body,
+            1,
+        ))),
+        None,
+    ];
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_3x3_auto_size_with_different_col_spans() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("1".into(), 1),
+            Cell::new("22".into(), 1),
+            Cell::new("1".into(), 1),
+        ]),
+        Row::new(vec![
+            Cell::new("1234567".into(), 2),
+            Cell::new("1".into(), 1),
+        ]),
+        Row::new(vec![
+            Cell::new("1".into(), 1),
+            Cell::new("2\n1234".into(), 2),
+        ]),
+    ])
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .column_width(10)
+    .auto_size(true)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "1.. 22. 1",
+        "1234567 1",
+        "1.. 2....",
+        "... 1234.",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_2x2_total_width_with_min_column_widths() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("1".into(), 1), Cell::new("1".into(), 1)]),
+        Row::new(vec![Cell::new("2".into(), 2)]),
+    ])
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .column_widths(vec![4, 36])
+    .min_column_widths(vec![3])
+    .total_width(21)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n",
+        "1.. 1................",
+        "2....................",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_2x2_wrap_top() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("1".into(), 1),
+            Cell::builder("lorem ipsum dolor".into(), 1)
+                .overflow(Overflow::Wrap)
+                .build(),
+        ]),
+        Row::new(vec![
+            Cell::new("truncated".into(), 1),
+            Cell::new("1".into(), 1),
+        ]),
+    ])
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .column_width(5)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "1.... lorem",
+        "..... ipsum",
+        "..... dolor",
+        "trunc 1....",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_2x1_ellipsis() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::new("/usr/local/bin".into(), 1),
+        Cell::new("description".into(), 1),
+    ])])
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .default_overflow(Overflow::Ellipsis {
+        marker: "...".into(),
+        position: EllipsisPosition::End,
+    })
+    .column_width(9)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n",
+        "/usr/l... descri...",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn test_grid_2x2_wide_chars() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("日本".into(), 1),
+            Cell::new("e\u{301}".into(), 1),
+        ]),
+        Row::new(vec![
+            Cell::new("日本語".into(), 1),
+            Cell::new("1".into(), 1),
+        ]),
+    ])
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .column_width(5)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n",
+        "日本. e\u{301}....",
+        "日本. 1....",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_2x1_styled_content() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::new("\x1b[31mred\x1b[0m".into(), 1),
+        Cell::new("\x1b[1mbold text\x1b[0m".into(), 1),
+    ])])
+    .default_h_align(HAlign::Right)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .column_width(5)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n",
+        "..\x1b[31mred\x1b[0m \x1b[1mbold \x1b[0m",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_3x3_ascii_borders_with_different_col_spans() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("3".into(), 3)]),
+        Row::new(vec![Cell::new("1".into(), 1), Cell::new("2".into(), 2)]),
+        Row::new(vec![Cell::new("2".into(), 2), Cell::new("1".into(), 1)]),
+    ])
+    .default_h_align(HAlign::Center)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .border_style(BorderStyle::Ascii)
+    .column_width(3)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n{}\n",
+        "+-----------+",
+        "|.....3.....|",
+        "+---+-------+",
+        "|.1.|...2...|",
+        "+---+---+---+",
+        "|...2...|.1.|",
+        "+-------+---+",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_2x2_light_frame_only() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("1".into(), 1), Cell::new("1".into(), 1)]),
+        Row::new(vec![Cell::new("1".into(), 1), Cell::new("1".into(), 1)]),
+    ])
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .border_style(BorderStyle::Light)
+    .borders(Borders::FRAME)
+    .column_width(3)
+    .padding_size(2)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "┌────────┐",
+        "│1..  1..│",
+        "│1..  1..│",
+        "└────────┘",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_2x2_rounded_borders_fit_total_width() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("1".into(), 1), Cell::new("1".into(), 1)]),
+        Row::new(vec![Cell::new("2".into(), 2)]),
+    ])
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .border_style(BorderStyle::Rounded)
+    .borders(Borders::COLUMNS)
+    .total_width(9)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "╭───┬───╮",
+        "│1..│1..│",
+        "│2......│",
+        "╰───────╯",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_empty_with_borders() {
+    let grid = Grid::builder(vec![])
+        .border_style(BorderStyle::Light)
+        .build();
+
+    let result = grid.to_string();
+
+    assert_eq!(result, "");
+}
+
+#[test]
+fn test_grid_with_empty_rows_and_borders() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![]),
+        Row::new(vec![Cell::new("a".into(), 1), Cell::new("b".into(), 1)]),
+        Row::new(vec![]),
+        Row::new(vec![Cell::new("c".into(), 2)]),
+    ])
+    .border_style(BorderStyle::Light)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n",
+        "┌─┬─┐",
+        "│a│b│",
+        "├─┴─┤",
+        "│c  │",
+        "└───┘",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_header_and_footer() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1), Cell::new("1".into(), 1)]),
+        Row::new(vec![Cell::new("b".into(), 1), Cell::new("2".into(), 1)]),
+    ])
+    .header(vec![Row::new(vec![
+        Cell::new("name".into(), 1),
+        Cell::new("value".into(), 1),
+    ])])
+    .header_options(Options {
+        h_align: Some(HAlign::Center),
+        blank_char: Some(' '),
+        ..Options::default()
+    })
+    .header_separator('=')
+    .footer(vec![Row::new(vec![
+        Cell::new("sum".into(), 1),
+        Cell::new("3".into(), 1),
+    ])])
+    .footer_separator('-')
+    .default_h_align(HAlign::Right)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .column_width(5)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n",
+        "name  value",
+        "===========",
+        "....a ....1",
+        "....b ....2",
+        "-----------",
+        "..sum ....3",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_header_and_footer_without_rows() {
+    let grid = Grid::builder(vec![])
+        .header(vec![Row::new(vec![Cell::new("H".into(), 1)])])
+        .footer(vec![Row::new(vec![Cell::new("F".into(), 1)])])
+        .header_separator('=')
+        .footer_separator('-')
+        .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "H",
+        "=",
+        "-",
+        "F",
+    );
+
+    assert_eq!(result, expected);
+
+    let mut result = Vec::new();
+    grid.write_rows_to(vec![], &mut result).unwrap();
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_with_header_repeated_on_pages() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1), Cell::new("1".into(), 1)]),
+        Row::new(vec![Cell::new("b".into(), 1), Cell::new("2".into(), 1)]),
+        Row::new(vec![Cell::new("c".into(), 1), Cell::new("3".into(), 1)]),
+    ])
+    .header(vec![Row::new(vec![Cell::new("header".into(), 2)])])
+    .header_separator('-')
+    .default_h_align(HAlign::Left)
+    .default_v_align(VAlign::Top)
+    .default_blank_char('.')
+    .border_style(BorderStyle::Ascii)
+    .borders(Borders::COLUMNS)
+    .column_width(3)
+    .page_size(2)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n",
+        "+-------+",
+        "|header.|",
+        "+---+---+",
+        "|a..|1..|",
+        "|b..|2..|",
+        "|header.|",
+        "+---+---+",
+        "|c..|3..|",
+        "+---+---+",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_row_spans_and_borders() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::builder("group".into(), 1)
+                .row_span(3)
+                .v_align(VAlign::Middle)
+                .build(),
+            Cell::new("a".into(), 1),
+            Cell::new("1".into(), 1),
+        ]),
+        Row::new(vec![
+            Cell::new("b".into(), 1),
+            Cell::builder("2\n3".into(), 1).row_span(2).build(),
+        ]),
+        Row::new(vec![Cell::new("c".into(), 1)]),
+        Row::new(vec![Cell::new("d".into(), 2), Cell::new("4".into(), 1)]),
+    ])
+    .default_blank_char('.')
+    .border_style(BorderStyle::Light)
+    .column_widths(vec![5, 1, 1])
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n",
+        "┌─────┬─┬─┐",
+        "│.....│a│1│",
+        "│.....├─┼─┤",
+        "│group│b│2│",
+        "│.....├─┤3│",
+        "│.....│c│.│",
+        "├─────┴─┼─┤",
+        "│d......│4│",
+        "└───────┴─┘",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_row_span_taller_than_rows() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::builder("x\ny\nz".into(), 1)
+                .row_span(2)
+                .v_align(VAlign::Bottom)
+                .build(),
+            Cell::new("a".into(), 1),
+        ]),
+        Row::new(vec![Cell::builder("b".into(), 1)
+            .v_align(VAlign::Bottom)
+            .build()]),
+        Row::new(vec![Cell::new("c".into(), 1), Cell::new("d".into(), 1)]),
+    ])
+    .default_blank_char('.')
+    .column_width(2)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "x. a.",
+        "y. ..",
+        "z. b.",
+        "c. d.",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_row_span_cut_at_end_of_section() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1), Cell::new("b".into(), 1)]),
+        Row::new(vec![Cell::new("c".into(), 1), Cell::new("d".into(), 1)]),
+    ])
+    .header(vec![Row::new(vec![
+        Cell::builder("h".into(), 1).row_span(3).build(),
+        Cell::new("i".into(), 1),
+    ])])
+    .default_blank_char('.')
+    .column_width(2)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n",
+        "h. i.",
+        "a. b.",
+        "c. d.",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_try_build_zero_default_colspan() {
+    let result = Grid::builder(vec![Row::new(vec![Cell::new("a".into(), 1)])])
+        .theme(Theme {
+            default_options: Options {
+                col_span: Some(0),
+                ..Options::default()
+            },
+            ..Theme::default()
+        })
+        .try_build()
+        .err();
+    let expected = Some(GridError::ZeroColSpan(Location::Grid));
+    assert_eq!(result, expected);
+}
+
+#[test]
+#[should_panic(expected = "Column span cannot be 0!")]
+fn test_grid_zero_default_colspan_panics() {
+    Grid::builder(vec![Row::new(vec![Cell::new("a".into(), 1)])]).default_colspan(0);
+}
+
+#[test]
+fn test_row_try_build_zero_default_colspan() {
+    let result = Row::builder(vec![Cell::new("a".into(), 1)])
+        .default_colspan(0)
+        .try_build()
+        .err();
+    let expected = Some(GridError::ZeroColSpan(Location::Row(Section::Body, 0)));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_row_try_build_zero_cell_rowspan() {
+    let result = Row::builder(vec![
+        Cell::new("a".into(), 1),
+        Cell::builder("b".into(), 1).row_span(0).build(),
+    ])
+    .try_build()
+    .err();
+    let expected = Some(GridError::ZeroRowSpan(Location::Cell(Section::Body, 0, 1)));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_try_build_zero_cell_colspan() {
+    let result = Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1)]),
+        Row::new(vec![
+            Cell::new("b".into(), 1),
+            Cell::builder("c".into(), 1).col_span(0).build(),
+        ]),
+    ])
+    .try_build()
+    .err();
+    let expected = Some(GridError::ZeroColSpan(Location::Cell(Section::Body, 1, 1)));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_try_build_overlapping_cells() {
+    let result = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("a".into(), 1),
+            Cell::builder("b".into(), 1).row_span(2).build(),
+        ]),
+        Row::new(vec![Cell::new("c".into(), 2)]),
+    ])
+    .try_build()
+    .err();
+    let expected = Some(GridError::OverlappingCells(Location::Cell(
+        Section::Body,
+        1,
+        0,
+    )));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_try_build_total_width_too_small() {
+    let result = Grid::builder(vec![Row::new(vec![
+        Cell::new("a".into(), 1),
+        Cell::new("b".into(), 1),
+    ])])
+    .min_column_widths(vec![3])
+    .total_width(4)
+    .try_build()
+    .err();
+    let expected = Some(GridError::TotalWidthTooSmall {
+        total_width: 4,
+        min_width: 5,
+    });
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_cell_try_new_zero_colspan() {
+    let result = Cell::try_new("a".into(), 0).err();
+    let expected = Some(GridError::ZeroColSpan(Location::Standalone));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_try_build_uneven_rows() {
+    let result = Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1), Cell::new("b".into(), 2)]),
+        Row::new(vec![Cell::new("c".into(), 1), Cell::new("d".into(), 1)]),
+        Row::new(vec![Cell::new("e".into(), 3)]),
+    ])
+    .header(vec![Row::new(vec![Cell::new("h".into(), 1)])])
+    .try_build()
+    .err();
+    let expected = Some(GridError::UnevenRows {
+        columns: 3,
+        rows: vec![
+            Location::Row(Section::Header, 0),
+            Location::Row(Section::Body, 1),
+        ],
+    });
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_ragged_rows_padded() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("a".into(), 1),
+            Cell::new("b".into(), 1),
+            Cell::new("c".into(), 1),
+        ]),
+        Row::new(vec![Cell::new("d".into(), 1)]),
+    ])
+    .default_blank_char('.')
+    .column_width(2)
+    .ragged_rows(RaggedRows::Pad)
+    .try_build()
+    .unwrap();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n",
+        "a. b. c.",
+        "d. .. ..",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_ragged_rows_stretched() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("a".into(), 1),
+            Cell::new("b".into(), 1),
+            Cell::new("c".into(), 1),
+        ]),
+        Row::new(vec![Cell::new("d".into(), 1), Cell::new("e".into(), 1)]),
+    ])
+    .default_blank_char('.')
+    .border_style(BorderStyle::Ascii)
+    .column_width(2)
+    .ragged_rows(RaggedRows::Stretch)
+    .try_build()
+    .unwrap();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n",
+        "+--+--+--+",
+        "|a.|b.|c.|",
+        "+--+--+--+",
+        "|d.|e....|",
+        "+--+-----+",
+    );
+
+    assert_eq!(result, expected);
+}
+
+fn report_rows() -> Vec<Row> {
+    vec![
+        Row::new(vec![
+            Cell::builder("group".into(), 1).row_span(2).build(),
+            Cell::new("a".into(), 1),
+        ]),
+        Row::new(vec![Cell::new("b".into(), 1)]),
+        Row::new(vec![Cell::new("c".into(), 1), Cell::new("d".into(), 1)]),
+        Row::new(vec![Cell::new("e".into(), 2)]),
+    ]
+}
+
+fn report_builder(rows: Vec<Row>) -> GridBuilder {
+    Grid::builder(rows)
+        .header(vec![Row::new(vec![
+            Cell::new("name".into(), 1),
+            Cell::new("value".into(), 1),
+        ])])
+        .footer(vec![Row::new(vec![Cell::new("total".into(), 2)])])
+        .header_separator('=')
+        .footer_separator('-')
+        .border_style(BorderStyle::Light)
+        .borders(Borders::COLUMNS)
+        .column_widths(vec![5, 5])
+}
+
+#[test]
+fn test_grid_write_to() {
+    let grid = report_builder(report_rows()).build();
+
+    let mut result = Vec::new();
+    grid.write_to(&mut result).unwrap();
+
+    let expected = grid.to_string();
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_write_rows_to_matches_display() {
+    let grid = report_builder(Vec::new()).build();
+
+    let mut result = Vec::new();
+    grid.write_rows_to(report_rows(), &mut result).unwrap();
+
+    let expected = report_builder(report_rows()).build().to_string();
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_write_rows_to_with_pages() {
+    let grid = report_builder(Vec::new()).page_size(2).build();
+
+    let mut result = Vec::new();
+    grid.write_rows_to(report_rows(), &mut result).unwrap();
+
+    let expected = report_builder(report_rows())
+        .page_size(2)
+        .build()
+        .to_string();
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_writer_after_grid_rows() {
+    let mut rows = report_rows();
+    let streamed = rows.split_off(2);
+    let grid = report_builder(rows).borders(Borders::ALL).build();
+
+    let mut writer = grid.writer(Vec::new()).unwrap();
+    for row in streamed {
+        writer.write_row(row).unwrap();
+    }
+    let result = writer.finish().unwrap();
+
+    let expected = report_builder(report_rows())
+        .borders(Borders::ALL)
+        .build()
+        .to_string();
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_writer_auto_size_without_header() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::new("hello".into(), 1),
+        Cell::new("world".into(), 1),
+    ])])
+    .auto_size(true)
+    .build();
+
+    let mut writer = grid.writer(Vec::new()).unwrap();
+    writer
+        .write_row(Row::new(vec![
+            Cell::new("a".into(), 1),
+            Cell::new("b".into(), 1),
+        ]))
+        .unwrap();
+    let result = writer.finish().unwrap();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n",
+        "hello world",
+        "a     b    ",
+    );
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_write_rows_to_auto_size_with_ragged_rows() {
+    let policies = [RaggedRows::Keep, RaggedRows::Pad, RaggedRows::Stretch];
+    for &policy in policies.iter() {
+        let grid = Grid::builder(vec![
+            Row::new(vec![Cell::new("a".into(), 1), Cell::new("b".into(), 1)]),
+            Row::new(vec![Cell::new("long text".into(), 1)]),
+        ])
+        .default_blank_char('.')
+        .ragged_rows(policy)
+        .auto_size(true)
+        .build();
+
+        let mut result = Vec::new();
+        grid.write_rows_to(vec![], &mut result).unwrap();
+
+        let expected = grid.to_string();
+
+        assert_eq!(String::from_utf8(result).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_grid_layout() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::builder("1".into(), 1).row_span(2).build(),
+            Cell::new("a".into(), 1),
+        ]),
+        Row::new(vec![Cell::new("b".into(), 1)]),
+    ])
+    .header(vec![Row::new(vec![
+        Cell::builder("id".into(), 1)
+            .h_align(HAlign::Center)
+            .build(),
+        Cell::new("name".into(), 1),
+    ])])
+    .default_h_align(HAlign::Right)
+    .default_blank_char('.')
+    .border_style(BorderStyle::Ascii)
+    .column_widths(vec![3, 4])
+    .build();
+
+    let layout = grid.layout();
+
+    let result = (layout.column_widths.clone(), layout.width, layout.height);
+    let expected = (vec![3, 4], 10, 7);
+    assert_eq!(result, expected);
+
+    let result = layout
+        .cells
+        .iter()
+        .map(|c| (c.section, c.row, c.index, c.line, c.x, c.width, c.height))
+        .collect::<Vec<_>>();
+    let expected = vec![
+        (Section::Header, 0, 0, 1, 1, 3, 1),
+        (Section::Header, 0, 1, 1, 5, 4, 1),
+        (Section::Body, 0, 0, 3, 1, 3, 3),
+        (Section::Body, 0, 1, 3, 5, 4, 1),
+        (Section::Body, 1, 0, 5, 5, 4, 1),
+    ];
+    assert_eq!(result, expected);
+
+    let result = &layout.cells[2];
+    let expected = &CellLayout {
+        section: Section::Body,
+        row: 0,
+        index: 0,
+        column: 0,
+        col_span: 1,
+        row_span: 2,
+        line: 3,
+        x: 1,
+        width: 3,
+        height: 3,
+        h_align: HAlign::Right,
+        v_align: VAlign::Top,
+        blank_char: '.',
+        padding: Padding::NONE,
+        style: Style::PLAIN,
+    };
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_layout_matches_rendered_cells() {
+    let grid = report_builder(report_rows()).build();
+    let layout = grid.layout();
+    let rendered = grid.to_string();
+    let lines = rendered.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), layout.height);
+    for cell in &layout.cells {
+        let line = lines[cell.line].chars().skip(cell.x).take(cell.width);
+        let content = grid_cell(&grid, cell.section, cell.row, cell.index);
+        let expected = content.lines().next().unwrap_or("");
+        assert!(line.collect::<String>().starts_with(expected));
+    }
+}
+
+fn grid_cell(grid: &Grid, section: Section, row: usize, index: usize) -> &str {
+    let rows = match section {
+        Section::Header => &grid.header,
+        Section::Body => &grid.rows,
+        Section::Footer => &grid.footer,
+    };
+    match &rows[row].cells[index].content {
+        CellContent::Text(text) => text,
+        CellContent::Grid(_) => "",
+    }
+}
+
+#[test]
+fn test_grid_layout_with_zero_row_span() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::builder("a".into(), 1).row_span(0).build(),
+        Cell::new("b".into(), 1),
+    ])])
+    .border_style(BorderStyle::Ascii)
+    .build();
+
+    let layout = grid.layout();
+
+    let result = layout
+        .cells
+        .iter()
+        .map(|c| (c.row_span, c.line, c.x, c.height))
+        .collect::<Vec<_>>();
+    let expected = vec![(1, 1, 1, 1), (1, 1, 3, 1)];
+    assert_eq!(result, expected);
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n",
+        "+-+-+",
+        "|a|b|",
+        "+-+-+",
+    );
+
+    assert_eq!(result, expected);
+}
+
+fn nested_grid() -> Grid {
+    Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1), Cell::new("b".into(), 1)]),
+        Row::new(vec![Cell::new("c".into(), 1), Cell::new("d".into(), 1)]),
+    ])
+    .column_width(1)
+    .build()
+}
+
+#[test]
+fn test_grid_with_nested_grid_fitted_into_cell() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::new("x".into(), 1),
+        Cell::new_grid(nested_grid(), 1),
+    ])])
+    .default_h_align(HAlign::Right)
+    .default_blank_char('.')
+    .border_style(BorderStyle::Ascii)
+    .column_widths(vec![3, 9])
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "+---+---------+",
+        "|..x|...a ...b|",
+        "|...|...c ...d|",
+        "+---+---------+",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_nested_grid_auto_size() {
+    let nested = Grid::builder(vec![Row::new(vec![
+        Cell::new("ab".into(), 1),
+        Cell::new("c".into(), 1),
+    ])])
+    .auto_size(true)
+    .build();
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("x".into(), 1),
+            Cell::builder(String::new(), 1).grid(nested).build(),
+        ]),
+        Row::new(vec![Cell::new("y".into(), 1), Cell::new("z".into(), 1)]),
+    ])
+    .default_blank_char('.')
+    .auto_size(true)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n",
+        "x ab c",
+        "y z...",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_try_build_invalid_nested_grid() {
+    let nested = Grid::builder(vec![Row::new(vec![Cell::builder("a".into(), 1)
+        .col_span(0)
+        .build()])])
+    .build();
+    let result = Grid::builder(vec![Row::new(vec![
+        Cell::new("x".into(), 1),
+        Cell::new_grid(nested, 1),
+    ])])
+    .try_build()
+    .err();
+    let expected = Some(GridError::Nested(
+        Location::Cell(Section::Body, 0, 1),
+        Box::new(GridError::ZeroColSpan(Location::Cell(Section::Body, 0, 0))),
+    ));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_column_options() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1), Cell::new("1".into(), 1)]),
+        Row::new(vec![Cell::new("b".into(), 1), Cell::new("22".into(), 1)]),
+        Row::new(vec![Cell::new("wide".into(), 2)]),
+        Row::builder(vec![Cell::new("c".into(), 1), Cell::new("3".into(), 1)])
+            .default_h_align(HAlign::Center)
+            .build(),
+    ])
+    .column_options(vec![
+        Options {
+            blank_char: Some('-'),
+            ..Options::default()
+        },
+        Options {
+            h_align: Some(HAlign::Right),
+            ..Options::default()
+        },
+    ])
+    .default_blank_char('.')
+    .column_width(4)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "a--- ...1",
+        "b--- ..22",
+        "wide-----",
+        "-c-- .3..",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_try_build_zero_column_colspan() {
+    let result = Grid::builder(vec![Row::new(vec![Cell::new("a".into(), 1)])])
+        .column_options(vec![
+            Options::default(),
+            Options {
+                col_span: Some(0),
+                ..Options::default()
+            },
+        ])
+        .try_build()
+        .err();
+    let expected = Some(GridError::ZeroColSpan(Location::Column(1)));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_decimal_aligned_column() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1), Cell::new("1.5".into(), 1)]),
+        Row::new(vec![
+            Cell::new("b".into(), 1),
+            Cell::new("100.25".into(), 1),
+        ]),
+        Row::new(vec![Cell::new("c".into(), 1), Cell::new("12".into(), 1)]),
+        Row::new(vec![
+            Cell::new("d".into(), 1),
+            Cell::new("-3.125".into(), 1),
+        ]),
+    ])
+    .column_options(vec![
+        Options::default(),
+        Options {
+            h_align: Some(HAlign::Decimal('.')),
+            ..Options::default()
+        },
+    ])
+    .default_blank_char('_')
+    .auto_size(true)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "a __1.5__",
+        "b 100.25_",
+        "c _12____",
+        "d _-3.125",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_decimal_aligned_column_and_text_header() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("1.5".into(), 1)]),
+        Row::new(vec![Cell::new("10.25".into(), 1)]),
+    ])
+    .header(vec![Row::new(vec![Cell::new("Amount".into(), 1)])])
+    .column_options(vec![Options {
+        h_align: Some(HAlign::Decimal('.')),
+        ..Options::default()
+    }])
+    .default_blank_char('_')
+    .auto_size(true)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n",
+        "Amount",
+        "__1.5_",
+        "_10.25",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_justified_cell() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::new("1".into(), 1),
+        Cell::builder("the quick brown fox jumps\nover it".into(), 1)
+            .h_align(HAlign::Justify)
+            .overflow(Overflow::Wrap)
+            .build(),
+    ])])
+    .default_blank_char('.')
+    .column_widths(vec![1, 10])
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n",
+        "1 the .quick",
+        ". brown .fox",
+        ". jumps.....",
+        ". over it...",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_padded_cells_and_borders() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("1".into(), 1), Cell::new("22".into(), 1)]),
+        Row::new(vec![Cell::new("333".into(), 1), Cell::new("4".into(), 1)]),
+    ])
+    .default_blank_char('.')
+    .default_padding(Padding::horizontal(1))
+    .border_style(BorderStyle::Ascii)
+    .auto_size(true)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n",
+        "+-----+----+",
+        "|.1...|.22.|",
+        "+-----+----+",
+        "|.333.|.4..|",
+        "+-----+----+",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_vertical_padding_and_gutter_char() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::builder("a".into(), 1)
+            .padding(Padding::uniform(1))
+            .build(),
+        Cell::new("b".into(), 1),
+    ])])
+    .default_blank_char('.')
+    .gutter_char('|')
+    .column_width(3)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n",
+        "...|b..",
+        ".a.|...",
+        "...|...",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_styled_cells() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::builder("a".into(), 1)
+            .style(Style::PLAIN.fg(Color::Red).bold())
+            .build(),
+        Cell::new("b".into(), 1),
+    ])])
+    .default_h_align(HAlign::Center)
+    .default_style(Style::PLAIN.bg(Color::Blue))
+    .column_width(3)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}{}{} {}\n",
+        "\x1b[31m \x1b[0m",
+        "\x1b[1;31ma\x1b[0m",
+        "\x1b[31m \x1b[0m",
+        "\x1b[44m b \x1b[0m",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_display_without_colors() {
+    let nested = Grid::builder(vec![Row::new(vec![Cell::new("b".into(), 1)])])
+        .default_style(Style::PLAIN.bg(Color::Blue))
+        .build();
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::builder("a".into(), 1)
+            .style(Style::PLAIN.fg(Color::Red).bold())
+            .build(),
+        Cell::new_grid(nested, 1),
+    ])])
+    .column_width(2)
+    .build();
+    let config = RenderConfig {
+        color: ColorChoice::Never,
+    };
+
+    let result = grid.display(config).to_string();
+
+    let expected = "a  b \n";
+
+    assert_eq!(result, expected);
+
+    let mut result = Vec::new();
+    grid.display(config).write_to(&mut result).unwrap();
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_display_always_matches_display() {
+    let grid = Grid::builder(vec![Row::new(vec![Cell::new("a".into(), 1)])])
+        .default_style(Style::PLAIN.underline())
+        .build();
+
+    let result = grid.display(RenderConfig::ALWAYS).to_string();
+
+    assert_eq!(result, grid.to_string());
+}
+
+#[test]
+fn test_grid_writer_without_colors() {
+    let grid = Grid::builder(vec![])
+        .default_style(Style::PLAIN.italic())
+        .column_width(1)
+        .build();
+    let config = RenderConfig {
+        color: ColorChoice::Never,
+    };
+
+    let mut result = Vec::new();
+    grid.display(config)
+        .write_rows_to(vec![Row::new(vec![Cell::new("a".into(), 1)])], &mut result)
+        .unwrap();
+
+    assert_eq!(String::from_utf8(result).unwrap(), "a\n");
+}
+
+#[test]
+fn test_grid_with_striped_rows() {
+    let rows = || {
+        vec![
+            Row::new(vec![Cell::new("a".into(), 1)]),
+            Row::new(vec![Cell::new("b\nb".into(), 1)]),
+            Row::new(vec![Cell::new("c".into(), 1)]),
+            Row::builder(vec![Cell::new("d".into(), 1)])
+                .default_blank_char('*')
+                .build(),
+            Row::new(vec![Cell::new("e".into(), 1)]),
+        ]
+    };
+    let builder = |rows| {
+        Grid::builder(rows)
+            .default_blank_char('.')
+            .stripe_options(Options {
+                blank_char: Some('-'),
+                ..Options::default()
+            })
+            .stripe_size(2)
+            .column_width(3)
+            .build()
+    };
+
+    let result = builder(rows()).to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n",
+        "a..",
+        "b..",
+        "b..",
+        "c--",
+        "d**",
+        "e..",
+    );
+
+    assert_eq!(result, expected);
+
+    let mut result = Vec::new();
+    let mut rest = rows();
+    let first = rest.drain(..1).collect();
+    builder(first).write_rows_to(rest, &mut result).unwrap();
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_with_striped_styles_under_cell_styles() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("a".into(), 1)]),
+        Row::new(vec![
+            Cell::new("b".into(), 1),
+            Cell::builder("c".into(), 1)
+                .style(Style::PLAIN.bold())
+                .build(),
+        ]),
+        Row::new(vec![Cell::new("d".into(), 1), Cell::new("e".into(), 1)]),
+        Row::new(vec![Cell::new("f".into(), 1)]),
+    ])
+    .stripe_options(Options {
+        style: Some(Style::PLAIN.bg(Color::BrightBlack)),
+        ..Options::default()
+    })
+    .ragged_rows(RaggedRows::Pad)
+    .column_width(1)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}{}{}\n{}\n{}{}{}\n",
+        "a  ",
+        "\x1b[100mb\x1b[0m", "\x1b[100m \x1b[0m", "\x1b[1mc\x1b[0m",
+        "d e",
+        "\x1b[100mf\x1b[0m", "\x1b[100m \x1b[0m", "\x1b[100m \x1b[0m",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_rules() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("ok".into(), 1), Cell::new("-1.5".into(), 1)]),
+        Row::new(vec![
+            Cell::new("FAILED".into(), 1),
+            Cell::builder("2".into(), 1).blank_char('*').build(),
+        ]),
+    ])
+    .header(vec![Row::new(vec![
+        Cell::new("status".into(), 1),
+        Cell::new("value".into(), 1),
+    ])])
+    .rule(|cell| Options {
+        h_align: Some(HAlign::Right).filter(|_| cell.column == 1),
+        ..Options::default()
+    })
+    .rule(|cell| Options {
+        blank_char: Some('-').filter(|_| cell.text(0) == Some("FAILED")),
+        ..Options::default()
+    })
+    .rule(|cell| Options {
+        h_align: Some(HAlign::Center).filter(|_| cell.section == Section::Header),
+        ..Options::default()
+    })
+    .auto_size(true)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n",
+        "status value",
+        "ok      -1.5",
+        "FAILED ----2",
+    );
+
+    assert_eq!(result, expected);
+
+    let mut result = Vec::new();
+    grid.write_rows_to(vec![], &mut result).unwrap();
+
+    assert_eq!(String::from_utf8(result).unwrap(), expected);
+}
+
+#[test]
+fn test_grid_with_rules_looking_up_columns() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![Cell::new("ab".into(), 2), Cell::new("-1".into(), 1)]),
+        Row::new(vec![
+            Cell::builder("c".into(), 1).row_span(2).build(),
+            Cell::new("d".into(), 1),
+            Cell::new("2".into(), 1),
+        ]),
+        Row::new(vec![Cell::new("e".into(), 1), Cell::new("-3".into(), 1)]),
+    ])
+    .rule(|cell| Options {
+        blank_char: Some('.').filter(|_| cell.text_at(2).is_some_and(|t| t.starts_with('-'))),
+        ..Options::default()
+    })
+    .rule(|cell| Options {
+        h_align: Some(HAlign::Right).filter(|_| cell.text_at(0).is_none()),
+        ..Options::default()
+    })
+    .column_width(3)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n",
+        "ab..... -1.",
+        "c   d   2  ",
+        "    ..e .-3",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_rule_styling_negative_numbers() {
+    let grid = Grid::builder(vec![Row::new(vec![
+        Cell::new("-1".into(), 1),
+        Cell::builder("-2".into(), 1)
+            .style(Style::PLAIN.bold())
+            .build(),
+        Cell::new("3".into(), 1),
+    ])])
+    .rule(|cell| Options {
+        style: cell
+            .content()
+            .parse::<f64>()
+            .ok()
+            .filter(|n| *n < 0.0)
+            .map(|_| Style::PLAIN.fg(Color::Red)),
+        ..Options::default()
+    })
+    .column_width(2)
+    .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{} {} {}\n",
+        "\x1b[31m-1\x1b[0m",
+        "\x1b[31m-2\x1b[0m",
+        "3 ",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_debug_with_rules() {
+    let grid = Grid::builder(vec![Row::new(vec![Cell::new("a".into(), 1)])])
+        .rule(|_| Options::default())
+        .rule(|_| Options::default())
+        .build();
+
+    let result = format!("{:?}", grid);
+
+    assert!(result.starts_with("Grid { default_options: Options {"));
+    assert!(result.contains("rules: [2 rules]"));
+    assert!(result.contains("content: Text(\"a\")"));
+}
+
+fn themed_grid(theme: Theme) -> GridBuilder {
+    Grid::builder(vec![
+        Row::new(vec![Cell::new("1".into(), 1), Cell::new("22".into(), 1)]),
+        Row::new(vec![Cell::new("333".into(), 1), Cell::new("4".into(), 1)]),
+    ])
+    .header(vec![Row::new(vec![
+        Cell::new("a".into(), 1),
+        Cell::new("b".into(), 1),
+    ])])
+    .theme(theme)
+    .auto_size(true)
+}
+
+#[test]
+fn test_grid_with_plain_and_compact_themes() {
+    let result = [
+        themed_grid(Theme::plain()).build().to_string(),
+        themed_grid(Theme::compact()).build().to_string(),
+    ];
+
+    #[rustfmt::skip]
+    let expected = [
+        format!(
+            "{}\n{}\n{}\n{}\n",
+            "a    b ",
+            "-------",
+            "1    22",
+            "333  4 ",
+        ),
+        format!(
+            "{}\n{}\n{}\n",
+            "a   b ",
+            "1   22",
+            "333 4 ",
+        ),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_rounded_theme() {
+    let grid = themed_grid(Theme::rounded()).build();
+    let config = RenderConfig {
+        color: ColorChoice::Never,
+    };
+
+    let result = grid.display(config).to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n",
+        "╭─────┬────╮",
+        "│ a   │ b  │",
+        "├─────┼────┤",
+        "│ 1   │ 22 │",
+        "│ 333 │ 4  │",
+        "╰─────┴────╯",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_heavy_theme_overridden() {
+    let grid = themed_grid(Theme::heavy())
+        .header_options(Options::default())
+        .borders(Borders::FRAME)
+        .default_h_align(HAlign::Right)
+        .build();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n",
+        "┏━━━━━━━━━━┓",
+        "┃   a    b ┃",
+        "┣━━━━━━━━━━┫",
+        "┃   1   22 ┃",
+        "┃ 333    4 ┃",
+        "┗━━━━━━━━━━┛",
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_grid_with_ragged_rows_padded_and_borders() {
+    let grid = Grid::builder(vec![
+        Row::new(vec![
+            Cell::new("a".into(), 1),
+            Cell::new("b".into(), 1),
+            Cell::new("c".into(), 1),
+        ]),
+        Row::new(vec![Cell::new("d".into(), 1)]),
+    ])
+    .default_blank_char('.')
+    .border_style(BorderStyle::Ascii)
+    .column_width(2)
+    .ragged_rows(RaggedRows::Pad)
+    .try_build()
+    .unwrap();
+
+    let result = grid.to_string();
+
+    #[rustfmt::skip]
+    let expected = format!(
+        "{}\n{}\n{}\n{}\n{}\n",
+        "+--+--+--+",
+        "|a.|b.|c.|",
+        "+--+--+--+",
+        "|d.|.....|",
+        "+--+-----+",
+    );
+
+    assert_eq!(result, expected);
+}